@@ -19,10 +19,15 @@ impl Addon {
         Self::addon_dir().join("fonts")
     }
 
+    pub fn sounds_dir() -> PathBuf {
+        Self::addon_dir().join("sounds")
+    }
+
     pub fn create_dirs() {
         let _ = fs::create_dir_all(Self::packs_dir());
         let _ = fs::create_dir(Self::icons_dir());
         let _ = fs::create_dir(Self::fonts_dir());
+        let _ = fs::create_dir(Self::sounds_dir());
     }
 
     pub fn open_addon_folder(&self) {
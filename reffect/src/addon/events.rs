@@ -0,0 +1,104 @@
+use super::Addon;
+use crate::{
+    context::{
+        Context, CustomTimer, SquadRoster, SquadUpdate, TimerEvent, VariableEvent, Variables,
+    },
+    elements::ElementStateEvent,
+    tree::StateChange,
+};
+use nexus::{event::raise_event, event_subscribe};
+use std::{
+    ffi::{CStr, CString, c_char, c_void},
+    ptr,
+};
+
+impl Addon {
+    /// Subscribes to Nexus events from other addons.
+    pub fn subscribe_events() {
+        event_subscribe!(Variables::SET_EVENT => VariableEvent, |data| {
+            if let Some(event) = data {
+                match unsafe { event.name() } {
+                    Some(name) => Context::lock().set_variable(name, event.value, event.duration),
+                    None => log::warn!("Received variable set event with invalid name"),
+                }
+            }
+        })
+        .revert_on_unload();
+
+        event_subscribe!(Variables::REMOVE_EVENT => VariableEvent, |data| {
+            if let Some(event) = data {
+                match unsafe { event.name() } {
+                    Some(name) => Context::lock().remove_variable(name),
+                    None => log::warn!("Received variable remove event with invalid name"),
+                }
+            }
+        })
+        .revert_on_unload();
+
+        event_subscribe!(CustomTimer::START_EVENT => TimerEvent, |data| {
+            if let Some(event) = data {
+                match unsafe { event.name() } {
+                    Some(name) => Context::lock().start_timer(name),
+                    None => log::warn!("Received timer start event with invalid name"),
+                }
+            }
+        })
+        .revert_on_unload();
+
+        event_subscribe!(CustomTimer::CANCEL_EVENT => TimerEvent, |data| {
+            if let Some(event) = data {
+                match unsafe { event.name() } {
+                    Some(name) => Context::lock().cancel_timer(name),
+                    None => log::warn!("Received timer cancel event with invalid name"),
+                }
+            }
+        })
+        .revert_on_unload();
+
+        event_subscribe!(SquadRoster::ACCOUNT_EVENT => c_char, |data| {
+            if let Some(account) = data {
+                match unsafe { CStr::from_ptr(account) }.to_str() {
+                    Ok(account) => Context::lock().set_account_name(account),
+                    Err(_) => log::warn!("Received invalid account name"),
+                }
+            }
+        })
+        .revert_on_unload();
+
+        event_subscribe!(SquadRoster::UPDATE_EVENT => SquadUpdate, |data| {
+            if let Some(update) = data {
+                let mut ctx = Context::lock();
+                for user in unsafe { update.users() } {
+                    if let Some(account) = unsafe { user.account() } {
+                        ctx.update_squad_member(account, user.member_subgroup());
+                    }
+                }
+            }
+        })
+        .revert_on_unload();
+
+        raise_event(SquadRoster::REQUEST_ACCOUNT_EVENT, ptr::null());
+    }
+
+    /// Publishes element state changes to other addons.
+    ///
+    /// Must not be called while holding the context lock.
+    pub fn publish_states(states: Vec<StateChange>) {
+        for change in states {
+            let pack = CString::new(change.pack).unwrap_or_default();
+            let element = CString::new(change.element).unwrap_or_default();
+            let source = CString::new(change.source).unwrap_or_default();
+            let event = ElementStateEvent {
+                pack: pack.as_ptr(),
+                element: element.as_ptr(),
+                source: source.as_ptr(),
+                visible: change.state.visible,
+                conditions: change.state.conditions,
+            };
+            raise_event(
+                ElementStateEvent::ID,
+                &event as *const ElementStateEvent as *const c_void,
+            );
+        }
+    }
+}
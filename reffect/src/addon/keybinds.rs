@@ -0,0 +1,30 @@
+use super::Addon;
+use crate::context::Context;
+use nexus::keybind::{keybind_handler, register_keybind_with_string, unregister_keybind};
+
+impl Addon {
+    /// Registers keybinds for new toggles, profiles & timers and unregisters removed ones.
+    pub fn sync_keybinds(&mut self, ctx: &Context) {
+        let ids = ctx.keybind_ids().collect::<Vec<_>>();
+
+        for id in self.keybinds.iter().filter(|id| !ids.contains(id)) {
+            log::debug!("Unregistering keybind {id}");
+            unregister_keybind(id);
+        }
+        for id in ids.iter().filter(|id| !self.keybinds.contains(id)) {
+            log::debug!("Registering keybind {id}");
+            register_keybind_with_string(
+                id,
+                keybind_handler!(|id, is_release| {
+                    if !is_release {
+                        Context::lock().handle_keybind(id);
+                    }
+                }),
+                "(null)",
+            )
+            .revert_on_unload();
+        }
+
+        self.keybinds = ids;
+    }
+}
@@ -1,9 +1,12 @@
 mod dirs;
+mod events;
 mod fonts;
+mod keybinds;
 mod packs;
 mod ui;
 
 use crate::{
+    audio::SystemAudio,
     context::Context,
     elements::Pack,
     internal::{Interface, Internal},
@@ -19,10 +22,13 @@ static ADDON: OnceLock<Mutex<Addon>> = OnceLock::new();
 
 #[derive(Debug)]
 pub struct Addon {
+    audio: SystemAudio,
     debug: bool,
     create_error: bool,
+    keybinds: Vec<String>,
     links: Links,
     packs: Vec<Pack>,
+    pending_update: bool,
     settings: GeneralSettings,
     worker: Option<StoppableWorker>,
 }
@@ -34,10 +40,13 @@ impl Addon {
 
     pub fn new() -> Self {
         Self {
+            audio: SystemAudio::new(),
             debug: false,
             create_error: false,
+            keybinds: Vec::new(),
             links: Links::load(),
             packs: Vec::new(),
+            pending_update: false,
             settings: GeneralSettings::new(),
             worker: None,
         }
@@ -75,6 +84,8 @@ impl Addon {
 
         Internal::init();
 
+        Self::subscribe_events();
+
         Self::create_dirs();
 
         let mut addon = Self::lock();
@@ -82,6 +93,7 @@ impl Addon {
         if let Some(settings) = AddonSettings::try_load() {
             settings.apply(&mut addon.settings, &mut ctx);
         }
+        addon.sync_keybinds(&ctx);
         addon.worker = Context::create_worker(addon.links.clone());
         addon.load_packs(&mut ctx);
     }
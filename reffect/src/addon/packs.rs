@@ -1,5 +1,5 @@
 use super::Addon;
-use crate::{context::Context, elements::Pack, file::TempFile, tree::Updater};
+use crate::{audio::Sound, context::Context, elements::Pack, file::TempFile, tree::Updater};
 use rfd::FileDialog;
 use std::{fs, thread};
 
@@ -7,6 +7,8 @@ impl Addon {
     pub fn load_packs(&mut self, ctx: &mut Context) {
         let dir = Self::packs_dir();
         log::info!("Loading packs from \"{}\"", dir.display());
+        // sound files may have changed on disk
+        Sound::clear_loaded();
 
         Self::create_dirs();
         match fs::read_dir(&dir) {
@@ -24,7 +26,9 @@ impl Addon {
                 }
                 log::info!("Loaded {} packs", self.packs.len());
 
-                Updater::force_update(ctx, &mut self.packs);
+                self.pending_update = Updater::force_update(ctx, &mut self.packs)
+                    .pending
+                    .into_inner();
             }
             Err(err) => log::error!("Failed to read packs directory: {err}"),
         }
@@ -8,6 +8,7 @@ use crate::{
     },
     error::Error,
     internal::{Interface, Internal},
+    render::debug_optional,
 };
 use nexus::imgui::{StyleColor, TreeNode, TreeNodeFlags, Ui, Window};
 use std::{
@@ -26,6 +27,13 @@ impl Addon {
                 ui.text(format!("Show elements: {}", ctx.ui.should_show()));
 
                 ui.text(format!("Combat: {}", ctx.ui.combat));
+                ui.text(format!("Text input: {}", ctx.ui.text_input));
+                ui.text(format!("Game focus: {}", ctx.ui.game_focus));
+                ui.text(format!("Compass top: {}", ctx.ui.compass_top));
+                ui.text(format!("Competitive: {}", ctx.ui.competitive));
+                ui.text(format!("Commander: {}", ctx.ui.commander));
+
+                ui.text(format!("Character: {}", ctx.player.name));
 
                 ui.text("Profession:");
                 ui.same_line();
@@ -182,21 +190,16 @@ impl Addon {
 
                 debug_result_tree(ui, "grp", "Group", &ctx.group, |group| {
                     ui.text(format!("Group Type: {}", group.group_type));
+                    debug_optional(ui, "Subgroup", group.subgroup);
 
                     for (i, member) in group.members.iter().enumerate() {
-                        let label = format!(
-                            "Member {}: {}",
-                            i + 1,
-                            if let Some(acc) = &member.account {
-                                acc.strip_prefix(':').unwrap_or(acc)
-                            } else {
-                                "-"
-                            }
-                        );
+                        let label =
+                            format!("Member {}: {}", i + 1, member.account_name().unwrap_or("-"));
                         TreeNode::new(i.to_string())
                             .label::<String, _>(label)
                             .flags(TreeNodeFlags::SPAN_AVAIL_WIDTH)
                             .build(ui, || {
+                                debug_optional(ui, "Subgroup", member.subgroup);
                                 debug_result_tree(
                                     ui,
                                     "res",
@@ -3,7 +3,7 @@ mod editor;
 mod options;
 
 use super::Addon;
-use crate::{context::Context, elements::RenderCtx, render::Io, tree::Updater};
+use crate::{audio::play_sounds, context::Context, elements::RenderCtx, render::Io, tree::Updater};
 use nexus::{
     font::{font_receive, get_font},
     imgui::Ui,
@@ -43,8 +43,15 @@ impl Addon {
         let mut ctx = Context::lock();
 
         ctx.prepare_render(&self.links);
-        if ctx.has_any_update_or_edit() {
-            Updater::update(&ctx, &mut self.packs);
+        let mut timers = Vec::new();
+        let mut states = Vec::new();
+        let mut sounds = Vec::new();
+        if ctx.has_any_update_or_edit() || self.pending_update {
+            let output = Updater::update(&ctx, &mut self.packs);
+            self.pending_update = output.pending.into_inner();
+            timers = output.timers.into_inner();
+            states = output.states.into_inner();
+            sounds = output.sounds.into_inner();
         }
 
         self.render_displays(ui, &ctx);
@@ -55,6 +62,16 @@ impl Addon {
 
         self.render_popups(ui);
         ctx.reset_after_render();
+
+        // started timers are picked up in the next update
+        for timer in timers {
+            ctx.start_timer(&timer);
+        }
+
+        // other addons may call back into us while handling the event
+        drop(ctx);
+        Self::publish_states(states);
+        play_sounds(&mut self.audio, sounds, self.settings.mute_sounds);
     }
 
     pub fn render_displays(&mut self, ui: &Ui, ctx: &Context) {
@@ -1,9 +1,13 @@
 use super::Addon;
 use crate::{
-    context::Context,
+    action::Action,
+    context::{
+        Context, CustomEvent, CustomMapGroup, CustomTimer, CustomToggle, ToggleProfile, Update,
+    },
     metadata::{BUILD_TIME, COMMIT, RUSTC},
+    render::{Validation, helper, input_seconds, input_u32, item_context_menu, map_select},
 };
-use nexus::imgui::{TreeNodeFlags, Ui};
+use nexus::imgui::{CollapsingHeader, TreeNodeFlags, Ui};
 
 impl Addon {
     pub fn render_options(&mut self, ui: &Ui) {
@@ -67,6 +71,26 @@ impl Addon {
                 }
             }
 
+            if let Some(_token) = ui.tab_item("Map Groups") {
+                render_map_groups(ui, ctx);
+            }
+
+            if let Some(_token) = ui.tab_item("Events") {
+                render_events(ui, ctx);
+            }
+
+            if let Some(_token) = ui.tab_item("Timers") {
+                if render_timers(ui, ctx) {
+                    self.sync_keybinds(ctx);
+                }
+            }
+
+            if let Some(_token) = ui.tab_item("Toggles") {
+                if render_toggles(ui, ctx) {
+                    self.sync_keybinds(ctx);
+                }
+            }
+
             if let Some(_token) = ui.tab_item("?") {
                 ui.text("Reffect");
                 ui.same_line();
@@ -91,6 +115,268 @@ impl Addon {
     }
 }
 
+fn render_map_groups(ui: &Ui, ctx: &mut Context) {
+    let mut changed = false;
+    let mut group_action = Action::new();
+
+    for (i, group) in ctx.map_groups.iter_mut().enumerate() {
+        let _id = ui.push_id(i as i32);
+        if CollapsingHeader::new(format!("{}###group{i}", group.name))
+            .flags(TreeNodeFlags::SPAN_AVAIL_WIDTH)
+            .build(ui)
+        {
+            changed |= group_action
+                .input_with_buttons(ui, i, || ui.input_text("##name", &mut group.name).build());
+            ui.same_line();
+            ui.text("Name");
+
+            let mut action = Action::new();
+            for (j, id) in group.ids.iter_mut().enumerate() {
+                let _id = ui.push_id(j as i32);
+                changed |= action.input_with_buttons(ui, j, || input_u32(ui, "##id", id, 0, 0));
+
+                ui.same_line();
+                ui.text(format!("Map Id {}", j + 1));
+            }
+            if ui.button("Add Map") {
+                group.ids.push(0);
+                changed = true;
+            }
+            item_context_menu("mapaddctx", || {
+                if let Some(selection) = map_select(ui, &[]) {
+                    group.ids.extend(selection.ids());
+                    changed = true;
+                }
+            });
+            helper(ui, || ui.text("Right click to insert commonly used maps"));
+
+            changed |= action.perform(&mut group.ids);
+            ui.spacing();
+        }
+    }
+
+    if ui.button("Add Map Group") {
+        let id = ctx.next_map_group_id();
+        let name = format!("Map Group {}", ctx.map_groups.len() + 1);
+        ctx.map_groups.push(CustomMapGroup::new(id, name));
+        changed = true;
+    }
+    helper(ui, || {
+        ui.text("Named map groups can be used in map filters of all packs");
+        ui.text("Groups can be renamed without updating filters");
+    });
+
+    changed |= group_action.perform(&mut ctx.map_groups);
+
+    if changed {
+        ctx.updates.insert(Update::Map);
+    }
+}
+
+fn render_events(ui: &Ui, ctx: &mut Context) {
+    let mut changed = false;
+    let mut event_action = Action::new();
+
+    for (i, event) in ctx.events.iter_mut().enumerate() {
+        let _id = ui.push_id(i as i32);
+        if CollapsingHeader::new(format!("{}###event{i}", event.name))
+            .flags(TreeNodeFlags::SPAN_AVAIL_WIDTH)
+            .build(ui)
+        {
+            changed |= event_action
+                .input_with_buttons(ui, i, || ui.input_text("##name", &mut event.name).build());
+            ui.same_line();
+            ui.text("Name");
+
+            let validation = match event.parse_schedule() {
+                Ok(schedule) => Validation::Confirm(schedule.to_string()),
+                Err(err) => Validation::Error(err.to_string()),
+            };
+            changed |= validation.for_item(ui, || {
+                ui.input_text("Schedule", &mut event.schedule).build()
+            });
+            helper(ui, || {
+                ui.text("Times are in UTC, for example:");
+                ui.text("every 2h at :15, duration 15m");
+                ui.text("daily at 0:00");
+                ui.text("weekly mon at 7:30");
+            });
+
+            ui.spacing();
+        }
+    }
+
+    if ui.button("Add Event") {
+        let name = format!("Event {}", ctx.events.len() + 1);
+        ctx.events.push(CustomEvent::new(name));
+        changed = true;
+    }
+    helper(ui, || {
+        ui.text("Named events can be used as scheduled event trigger in all packs");
+        ui.text("Events with the same name as a built-in event replace it");
+    });
+
+    changed |= event_action.perform(&mut ctx.events);
+
+    if changed {
+        ctx.update_events();
+        ctx.updates.insert(Update::Time);
+    }
+}
+
+/// Renders timers, returning whether keybinds need to be synced.
+fn render_timers(ui: &Ui, ctx: &mut Context) -> bool {
+    let mut sync = false;
+    let mut changed = false;
+    let mut timer_action = Action::new();
+    let now = ctx.now;
+
+    for (i, timer) in ctx.timers.iter_mut().enumerate() {
+        let _id = ui.push_id(i as i32);
+        if CollapsingHeader::new(format!("{}###timer{i}", timer.name))
+            .flags(TreeNodeFlags::SPAN_AVAIL_WIDTH)
+            .build(ui)
+        {
+            timer_action.input_with_buttons(ui, i, || {
+                ui.input_text("##name", &mut timer.name).build();
+                sync |= ui.is_item_deactivated_after_edit();
+            });
+            ui.same_line();
+            ui.text("Name");
+
+            changed |= input_seconds(ui, "Duration", &mut timer.duration);
+
+            if ui.button("Start") {
+                timer.start(now);
+                changed = true;
+            }
+            ui.same_line();
+            if ui.button("Cancel") {
+                timer.cancel();
+                changed = true;
+            }
+            ui.same_line();
+            match timer.remaining(now) {
+                Some(remaining) => {
+                    ui.text(format!("Running, {:.1}s left", remaining as f32 / 1000.0))
+                }
+                None => ui.text_disabled("Stopped"),
+            }
+
+            ui.spacing();
+        }
+    }
+
+    if ui.button("Add Timer") {
+        let name = format!("Timer {}", ctx.timers.len() + 1);
+        ctx.timers.push(CustomTimer::new(name));
+        sync = true;
+    }
+    helper(ui, || {
+        ui.text("Named timers can be used as custom timer trigger in all packs");
+        ui.text("Each timer registers start & cancel keybinds in the Nexus keybind settings");
+        ui.text("Triggers can start timers when becoming active");
+        ui.text("Timers are cancelled on map change");
+    });
+
+    sync |= timer_action.perform(&mut ctx.timers);
+
+    if changed || sync {
+        ctx.updates.insert(Update::Timers);
+    }
+
+    sync
+}
+
+/// Renders toggles & profiles, returning whether keybinds need to be synced.
+fn render_toggles(ui: &Ui, ctx: &mut Context) -> bool {
+    let mut changed = false;
+    let mut sync = false;
+    let toggles = &mut ctx.toggles;
+
+    let mut toggle_action = Action::new();
+    for (i, toggle) in toggles.toggles.iter_mut().enumerate() {
+        let _id = ui.push_id(i as i32);
+        changed |= ui.checkbox("##enabled", &mut toggle.enabled);
+        ui.same_line();
+        toggle_action.input_with_buttons(ui, i, || {
+            ui.input_text("##name", &mut toggle.name).build();
+            sync |= ui.is_item_deactivated_after_edit();
+        });
+        ui.same_line();
+        ui.text(format!("Toggle {}", i + 1));
+    }
+
+    if ui.button("Add Toggle") {
+        let name = format!("Toggle {}", toggles.toggles.len() + 1);
+        toggles.toggles.push(CustomToggle::new(name));
+        sync = true;
+    }
+    helper(ui, || {
+        ui.text("Named toggles can be assigned to packs & elements");
+        ui.text("Each toggle registers a keybind in the Nexus keybind settings");
+        ui.text("Elements reference toggles by name, renaming requires updating them");
+    });
+
+    sync |= toggle_action.perform(&mut toggles.toggles);
+
+    ui.spacing();
+
+    let mut profile_action = Action::new();
+    for (i, profile) in toggles.profiles.iter_mut().enumerate() {
+        let _id = ui.push_id(format!("profile{i}"));
+        if CollapsingHeader::new(format!("{}###profile{i}", profile.name))
+            .flags(TreeNodeFlags::SPAN_AVAIL_WIDTH)
+            .build(ui)
+        {
+            profile_action.input_with_buttons(ui, i, || {
+                ui.input_text("##name", &mut profile.name).build();
+                sync |= ui.is_item_deactivated_after_edit();
+            });
+            ui.same_line();
+            ui.text("Name");
+
+            for toggle in &toggles.toggles {
+                let mut enabled = profile.enabled.contains(&toggle.name);
+                if ui.checkbox(&toggle.name, &mut enabled) {
+                    if enabled {
+                        profile.enabled.push(toggle.name.clone());
+                    } else {
+                        profile.enabled.retain(|name| *name != toggle.name);
+                    }
+                }
+            }
+
+            ui.spacing();
+        }
+    }
+
+    if ui.button("Add Profile") {
+        let name = format!("Profile {}", toggles.profiles.len() + 1);
+        let enabled = toggles
+            .toggles
+            .iter()
+            .filter(|toggle| toggle.enabled)
+            .map(|toggle| toggle.name.clone())
+            .collect();
+        toggles.profiles.push(ToggleProfile::new(name, enabled));
+        sync = true;
+    }
+    helper(ui, || {
+        ui.text("Profiles enable their selected toggles and disable all others");
+        ui.text("Each profile registers a keybind in the Nexus keybind settings");
+        ui.text("New profiles use the currently enabled toggles");
+    });
+
+    sync |= profile_action.perform(&mut toggles.profiles);
+
+    if changed || sync {
+        ctx.updates.insert(Update::Toggles);
+    }
+
+    sync
+}
+
 fn copyable_text(ui: &Ui, text: impl AsRef<str>) {
     let text = text.as_ref();
     ui.text(text);
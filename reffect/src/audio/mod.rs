@@ -0,0 +1,51 @@
+mod sound;
+mod system;
+mod wav;
+
+pub use self::{sound::*, system::*};
+
+/// Audio backend playing sounds.
+pub trait AudioBackend {
+    /// Plays the sound with the given volume.
+    fn play(&mut self, sound: &Sound, volume: f32);
+}
+
+/// Request to play a sound.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoundPlay {
+    /// Sound to play.
+    pub sound: Sound,
+
+    /// Volume between `0.0` and `1.0`.
+    pub volume: f32,
+}
+
+/// Plays the requested sounds unless muted.
+pub fn play_sounds(
+    backend: &mut impl AudioBackend,
+    sounds: impl IntoIterator<Item = SoundPlay>,
+    muted: bool,
+) {
+    if !muted {
+        for SoundPlay { sound, volume } in sounds {
+            backend.play(&sound, volume);
+        }
+    }
+}
+
+/// Audio backend recording played sounds.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub struct RecordingAudio {
+    pub played: Vec<SoundPlay>,
+}
+
+#[cfg(test)]
+impl AudioBackend for RecordingAudio {
+    fn play(&mut self, sound: &Sound, volume: f32) {
+        self.played.push(SoundPlay {
+            sound: sound.clone(),
+            volume,
+        });
+    }
+}
@@ -0,0 +1,213 @@
+use super::wav;
+use crate::{
+    addon::Addon,
+    enums::check_variant_array,
+    lockbox::Lockbox,
+    render::{Validation, enum_combo},
+};
+use const_default::ConstDefault;
+use nexus::imgui::{ComboBoxFlags, Ui};
+use rfd::FileDialog;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    thread,
+};
+use strum::{AsRefStr, EnumCount, EnumIter, IntoStaticStr, VariantArray};
+
+/// Sound source.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    IntoStaticStr,
+    AsRefStr,
+    EnumIter,
+    EnumCount,
+    Serialize,
+    Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Sound {
+    /// Built-in sound.
+    #[strum(serialize = "Built-in")]
+    Builtin(BuiltinSound),
+
+    /// WAV file relative to the sounds folder.
+    File(PathBuf),
+}
+
+impl VariantArray for Sound {
+    const VARIANTS: &'static [Self] = &[
+        Self::Builtin(BuiltinSound::DEFAULT),
+        Self::File(PathBuf::new()),
+    ];
+}
+
+const _: () = check_variant_array::<Sound>();
+
+impl ConstDefault for Sound {
+    const DEFAULT: Self = Self::Builtin(BuiltinSound::DEFAULT);
+}
+
+impl Default for Sound {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Loaded WAV data by sound, failed loads are not cached.
+static LOADED: Mutex<BTreeMap<Sound, Arc<[u8]>>> = Mutex::new(BTreeMap::new());
+
+impl Sound {
+    /// Loads the WAV data of the sound.
+    pub fn load(&self) -> io::Result<Vec<u8>> {
+        match self {
+            Self::Builtin(sound) => Ok(sound.generate()),
+            Self::File(path) => fs::read(Addon::sounds_dir().join(path)),
+        }
+    }
+
+    /// Loads the WAV data of the sound, reusing previously loaded data.
+    pub fn load_cached(&self) -> io::Result<Arc<[u8]>> {
+        let mut loaded = LOADED.lock().unwrap();
+        if let Some(data) = loaded.get(self) {
+            Ok(data.clone())
+        } else {
+            let data: Arc<[u8]> = self.load()?.into();
+            loaded.insert(self.clone(), data.clone());
+            Ok(data)
+        }
+    }
+
+    /// Discards previously loaded data of the sound.
+    pub fn invalidate(&self) {
+        LOADED.lock().unwrap().remove(self);
+    }
+
+    /// Discards all previously loaded sound data.
+    pub fn clear_loaded() {
+        LOADED.lock().unwrap().clear();
+    }
+
+    /// Renders the sound selection.
+    pub fn render_select(&mut self, ui: &Ui) -> bool {
+        let mut changed = enum_combo(ui, "Sound", self, ComboBoxFlags::empty()).is_some();
+
+        changed |= match self {
+            Self::Builtin(sound) => {
+                enum_combo(ui, "Built-in", sound, ComboBoxFlags::empty()).is_some()
+            }
+            Self::File(path) => Self::render_file_input(ui, path),
+        };
+
+        if changed {
+            // reload in case the file changed on disk
+            self.invalidate();
+        }
+
+        changed
+    }
+
+    fn render_file_input(ui: &Ui, path: &mut PathBuf) -> bool {
+        // we assume this stays in place, otherwise we consider the file dialog invalidated
+        let id = path as *mut _ as usize;
+
+        let validation = if path.is_absolute() {
+            Validation::Warn("Non-shareable absolute file path")
+        } else {
+            Validation::Ok
+        };
+        validation.for_item(ui, || {
+            ui.input_text("##path", &mut path.display().to_string())
+                .hint("No file")
+                .auto_select_all(true)
+                .read_only(true)
+                .build()
+        });
+
+        static FILE: Lockbox<usize, PathBuf> = Lockbox::new();
+
+        ui.same_line();
+        if ui.button("Select") {
+            thread::spawn(move || {
+                let dir = Addon::sounds_dir();
+                if let Some(file) = FileDialog::new()
+                    .set_title("Select Sound")
+                    .set_directory(&dir)
+                    .add_filter("Sound", &["wav"])
+                    .pick_file()
+                {
+                    // try to get the relative path from sounds folder
+                    let file = match file.strip_prefix(dir) {
+                        Ok(relative) => relative.to_path_buf(),
+                        Err(_) => {
+                            log::warn!("Absolute file path \"{}\"", file.display());
+                            file
+                        }
+                    };
+                    FILE.write(id, file);
+                }
+            });
+        }
+
+        if let Some(file) = FILE.try_take(id) {
+            *path = file;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Built-in sound.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    IntoStaticStr,
+    AsRefStr,
+    EnumIter,
+    EnumCount,
+    VariantArray,
+    Serialize,
+    Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum BuiltinSound {
+    Beep,
+    Chime,
+    Alert,
+}
+
+impl ConstDefault for BuiltinSound {
+    const DEFAULT: Self = Self::Beep;
+}
+
+impl BuiltinSound {
+    /// Returns the tones as frequency in Hz and duration in milliseconds.
+    ///
+    /// A frequency of zero is silence.
+    pub const fn tones(&self) -> &'static [(f32, u32)] {
+        match self {
+            Self::Beep => &[(880.0, 150)],
+            Self::Chime => &[(660.0, 120), (880.0, 220)],
+            Self::Alert => &[(988.0, 90), (0.0, 60), (988.0, 90), (0.0, 60), (988.0, 90)],
+        }
+    }
+
+    /// Generates the WAV data of the sound.
+    pub fn generate(&self) -> Vec<u8> {
+        wav::tones(self.tones())
+    }
+}
@@ -0,0 +1,72 @@
+use super::{AudioBackend, Sound, wav};
+use std::fmt;
+use windows::{
+    Win32::Media::Audio::{PlaySoundW, SND_ASYNC, SND_FLAGS, SND_MEMORY, SND_NODEFAULT},
+    core::PCWSTR,
+};
+
+/// Audio backend using the system sound player.
+///
+/// Only a single sound plays at a time, a new sound interrupts the previous one.
+#[derive(Default)]
+pub struct SystemAudio {
+    /// Data of the currently playing sound, has to outlive playback.
+    playing: Option<Vec<u8>>,
+}
+
+impl SystemAudio {
+    /// Creates a new system audio backend.
+    pub const fn new() -> Self {
+        Self { playing: None }
+    }
+
+    /// Stops the currently playing sound.
+    pub fn stop(&mut self) {
+        if self.playing.take().is_some() {
+            let _ = unsafe { PlaySoundW(PCWSTR::null(), None, SND_FLAGS(0)) };
+        }
+    }
+}
+
+impl AudioBackend for SystemAudio {
+    fn play(&mut self, sound: &Sound, volume: f32) {
+        let mut data = match sound.load_cached() {
+            Ok(data) => data.to_vec(),
+            Err(err) => {
+                log::warn!("Failed to load sound {sound:?}: {err}");
+                return;
+            }
+        };
+        if !wav::scale_volume(&mut data, volume) {
+            log::warn!("Unsupported sound format for volume of {sound:?}");
+        }
+
+        // replaces the previous sound before its data is dropped
+        let played = unsafe {
+            PlaySoundW(
+                PCWSTR(data.as_ptr().cast()),
+                None,
+                SND_MEMORY | SND_ASYNC | SND_NODEFAULT,
+            )
+        };
+        if played.as_bool() {
+            self.playing = Some(data);
+        } else {
+            log::warn!("Failed to play sound {sound:?}");
+        }
+    }
+}
+
+impl Drop for SystemAudio {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+impl fmt::Debug for SystemAudio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SystemAudio")
+            .field("playing", &self.playing.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,113 @@
+use std::f32::consts::TAU;
+
+/// Sample rate of generated sounds.
+const SAMPLE_RATE: u32 = 22050;
+
+/// Fade duration at tone edges in samples to avoid clicks.
+const FADE: usize = 220;
+
+/// Generates 16-bit mono PCM WAV data from the given tones.
+///
+/// Tones are given as frequency in Hz and duration in milliseconds, a frequency of zero is silence.
+pub fn tones(tones: &[(f32, u32)]) -> Vec<u8> {
+    let mut samples = Vec::new();
+    for &(freq, duration) in tones {
+        let len = (SAMPLE_RATE * duration / 1000) as usize;
+        for i in 0..len {
+            let fade = (i.min(len - i) as f32 / FADE as f32).min(1.0);
+            let value = if freq > 0.0 {
+                fade * (TAU * freq * i as f32 / SAMPLE_RATE as f32).sin()
+            } else {
+                0.0
+            };
+            samples.push((value * 0.5 * i16::MAX as f32) as i16);
+        }
+    }
+    encode(&samples)
+}
+
+/// Encodes 16-bit mono PCM samples as WAV data.
+fn encode(samples: &[i16]) -> Vec<u8> {
+    let data_len = 2 * samples.len() as u32;
+    let mut wav = Vec::with_capacity(44 + data_len as usize);
+    wav.extend(b"RIFF");
+    wav.extend((36 + data_len).to_le_bytes());
+    wav.extend(b"WAVE");
+    wav.extend(b"fmt ");
+    wav.extend(16u32.to_le_bytes());
+    wav.extend(1u16.to_le_bytes()); // pcm
+    wav.extend(1u16.to_le_bytes()); // mono
+    wav.extend(SAMPLE_RATE.to_le_bytes());
+    wav.extend((2 * SAMPLE_RATE).to_le_bytes());
+    wav.extend(2u16.to_le_bytes());
+    wav.extend(16u16.to_le_bytes());
+    wav.extend(b"data");
+    wav.extend(data_len.to_le_bytes());
+    for sample in samples {
+        wav.extend(sample.to_le_bytes());
+    }
+    wav
+}
+
+/// Scales the samples of 16-bit PCM WAV data by the given volume.
+///
+/// Returns `false` if the data is not in a supported format.
+pub fn scale_volume(wav: &mut [u8], volume: f32) -> bool {
+    if wav.len() < 12 || &wav[0..4] != b"RIFF" || &wav[8..12] != b"WAVE" {
+        return false;
+    }
+
+    let mut is_pcm16 = false;
+    let mut pos = 12;
+    while pos + 8 <= wav.len() {
+        let id = &wav[pos..pos + 4];
+        let len = u32::from_le_bytes(wav[pos + 4..pos + 8].try_into().unwrap()) as usize;
+        let start = pos + 8;
+        let end = start.saturating_add(len).min(wav.len());
+        match id {
+            b"fmt " if len >= 16 && end - start >= 16 => {
+                let format = u16::from_le_bytes([wav[start], wav[start + 1]]);
+                let bits = u16::from_le_bytes([wav[start + 14], wav[start + 15]]);
+                is_pcm16 = format == 1 && bits == 16;
+            }
+            b"data" if is_pcm16 => {
+                for sample in wav[start..end].chunks_exact_mut(2) {
+                    let value = i16::from_le_bytes([sample[0], sample[1]]);
+                    let scaled = (value as f32 * volume.clamp(0.0, 1.0)) as i16;
+                    sample.copy_from_slice(&scaled.to_le_bytes());
+                }
+                return true;
+            }
+            _ => {}
+        }
+        // chunks are padded to even length
+        pos = start.saturating_add(len + len % 2);
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale() {
+        let mut wav = encode(&[1000, -1000, i16::MAX]);
+        assert!(scale_volume(&mut wav, 0.5));
+        assert_eq!(
+            &wav[44..],
+            [500i16, -500, i16::MAX / 2]
+                .map(i16::to_le_bytes)
+                .as_flattened()
+        );
+
+        assert!(!scale_volume(&mut b"not a wav file".to_vec(), 0.5));
+    }
+
+    #[test]
+    fn generate() {
+        let wav = tones(&[(440.0, 100), (0.0, 100)]);
+        assert_eq!(wav.len(), 44 + 2 * 2 * 2205);
+        assert!(wav[44 + 2 * 2205..].iter().all(|byte| *byte == 0));
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
         cycle_progress, enum_combo, helper, input_float_with_format, input_seconds,
         push_alpha_change,
     },
+    trigger::ProgressTrigger,
     util::non_zero_u32,
 };
 use const_default::ConstDefault;
@@ -23,9 +24,21 @@ pub struct Animation {
 
     /// Animation period in milliseconds.
     pub period: NonZero<u32>,
+
+    /// Time to play after the trigger changed in milliseconds.
+    ///
+    /// Plays continuously if zero.
+    pub on_change: u32,
 }
 
 impl Animation {
+    /// Checks whether the animation is currently playing.
+    pub fn is_playing(&self, ctx: &RenderCtx, trigger: &ProgressTrigger) -> bool {
+        self.on_change == 0
+            || ctx.edit.is_editing()
+            || trigger.changed_within(ctx.now, self.on_change)
+    }
+
     /// Renders the animation, returning a token to end its scope.
     pub fn animate<'ui>(&mut self, ui: &'ui Ui, ctx: &RenderCtx) -> impl Drop + 'ui {
         let progress = cycle_progress(ui, self.period);
@@ -37,6 +50,7 @@ impl ConstDefault for Animation {
     const DEFAULT: Self = Self {
         kind: AnimationKind::Pulse,
         period: non_zero_u32!(1000),
+        on_change: 0,
     };
 }
 
@@ -57,6 +71,12 @@ impl Animation {
         }
         helper(ui, || ui.text("Animation loop duration in seconds"));
 
+        input_seconds(ui, "On change", &mut self.on_change);
+        helper(ui, || {
+            ui.text("Time to play after the trigger gains or loses activity");
+            ui.text("Plays continuously if zero");
+        });
+
         self.kind.render_options(ui);
     }
 }
@@ -1,22 +1,26 @@
+mod publish;
+
+pub use self::publish::*;
+
 use super::{Anchor, ELEMENT_ID, Element, ElementAnchor, ElementType};
 use crate::{
-    action::ChildElementAction,
+    action::{ChildElementAction, ElementAction},
     clipboard::Clipboard,
     colors,
     context::EditState,
-    elements::{Animation, RenderCtx},
+    elements::{Animation, RenderCtx, SoundCue},
     enums::EnumStaticVariants,
     id::Id,
     math::ComponentWise,
     render::{
-        Rect, confirm_modal, helper_slider, input_pos, push_alpha_change,
+        Rect, confirm_modal, helper, helper_slider, input_pos, push_alpha_change,
         push_window_clip_rect_fullscreen, slider_percent,
     },
     serde::migrate,
     tree::Resizer,
     trigger::{FilterTrigger, ProgressTrigger},
 };
-use nexus::imgui::{Condition, MenuItem, MouseButton, StyleVar, Ui, Window};
+use nexus::imgui::{Condition, MenuItem, MouseButton, Selectable, StyleVar, Ui, Window};
 use serde::{Deserialize, Serialize};
 use std::mem;
 
@@ -36,6 +40,12 @@ pub struct Common {
     /// Custom name for editor.
     pub name: String,
 
+    /// Stable key for trigger references, optional.
+    pub key: String,
+
+    /// Name of the keybind toggle, not toggleable if empty.
+    pub toggle: String,
+
     /// Anchor.
     #[serde(deserialize_with = "migrate::<_, _, Anchor>")]
     pub anchor: ElementAnchor,
@@ -58,6 +68,15 @@ pub struct Common {
     /// Animation.
     pub animation: Option<Animation>,
 
+    /// Sound cue.
+    pub sound: Option<SoundCue>,
+
+    /// Whether to publish state changes as Nexus event.
+    pub publish: bool,
+
+    #[serde(skip)]
+    pub publish_state: PublishState,
+
     #[serde(skip)]
     pub dragging: bool,
 
@@ -76,7 +95,7 @@ impl Common {
         if ctx.edit.is_editing() {
             (self.enabled && ctx.is_edit_visible()) || ctx.edit.is_selected_or_parent(self.id)
         } else {
-            self.enabled && self.filter.is_active(ctx)
+            self.enabled && ctx.toggles.is_enabled(&self.toggle) && self.filter.is_active(ctx)
         }
     }
 
@@ -99,7 +118,11 @@ impl Common {
         }
 
         let alpha = push_alpha_change(ui, self.opacity);
-        let anim = self.animation.as_mut().map(|anim| anim.animate(ui, ctx));
+        let anim = self
+            .animation
+            .as_mut()
+            .filter(|anim| anim.is_playing(ctx, &self.trigger))
+            .map(|anim| anim.animate(ui, ctx));
         Token((anim, alpha))
     }
 
@@ -201,12 +224,18 @@ impl Common {
                 selected = true;
             }
         }
+        if !matches!(action.kind, ElementAction::None | ElementAction::Copy) {
+            state.set_tree_changed();
+        }
         action.perform(children);
         selected
     }
 
     /// Renders common context menu options.
-    pub fn render_context_menu(&mut self, ui: &Ui, children: Option<&mut Vec<Element>>) {
+    ///
+    /// Returns `true` if a child was added.
+    pub fn render_context_menu(&mut self, ui: &Ui, children: Option<&mut Vec<Element>>) -> bool {
+        let mut added = false;
         if let Some(children) = children {
             ui.menu("Create", || {
                 ElementType::with_variants(|variants| {
@@ -215,6 +244,7 @@ impl Common {
                         if MenuItem::new(name).build(ui) {
                             let new = Element::of_type(kind.clone());
                             children.push(new);
+                            added = true;
                         }
                     }
                 })
@@ -224,9 +254,11 @@ impl Common {
                 .enabled(Clipboard::has_some())
                 .build(ui)
             {
-                children.push(Clipboard::take().expect("paste without clipboard"))
+                children.push(Clipboard::take().expect("paste without clipboard"));
+                added = true;
             }
         }
+        added
     }
 
     /// Renders the resize modal.
@@ -240,10 +272,42 @@ impl Common {
     }
 
     /// Renders common options.
-    pub fn render_options(&mut self, ui: &Ui, ctx: &RenderCtx) {
+    ///
+    /// Returns `true` if the name, key or trigger changed.
+    pub fn render_options(&mut self, ui: &Ui, ctx: &RenderCtx) -> bool {
+        let mut changed = false;
+
         ui.checkbox("Enabled", &mut self.enabled);
 
-        ui.input_text("Name", &mut self.name).build();
+        changed |= ui.input_text("Name", &mut self.name).build();
+
+        changed |= ui.input_text("Key", &mut self.key).build();
+        helper(ui, || {
+            ui.text("Optional stable key for trigger references");
+            ui.text("Other elements in the pack can reference this by key or name path");
+        });
+
+        if let Some(_token) = ui.begin_combo("Toggle", self.toggle_label()) {
+            if Selectable::new("None")
+                .selected(self.toggle.is_empty())
+                .build(ui)
+            {
+                self.toggle.clear();
+            }
+            for toggle in &ctx.toggles.toggles {
+                let selected = toggle.name == self.toggle;
+                if Selectable::new(&toggle.name).selected(selected).build(ui) {
+                    self.toggle = toggle.name.clone();
+                }
+                if selected {
+                    ui.set_item_default_focus();
+                }
+            }
+        }
+        helper(ui, || {
+            ui.text("Keybind toggle to show or hide the element");
+            ui.text("Toggles are managed in the settings");
+        });
 
         self.anchor.render_select(ui);
         input_pos(&mut self.pos);
@@ -251,9 +315,26 @@ impl Common {
         slider_percent(ui, "Opacity", &mut self.opacity);
         helper_slider(ui);
 
+        ui.checkbox("Publish State", &mut self.publish);
+        helper(ui, || {
+            ui.text("Raises a Nexus event when visibility or active conditions change");
+            ui.text("Allows other addons to react to the element");
+        });
+
         ui.spacing();
 
-        self.trigger.render_options(ui, ctx);
+        changed |= self.trigger.render_options(ui, ctx);
+
+        changed
+    }
+
+    /// Returns the label for the selected toggle.
+    fn toggle_label(&self) -> &str {
+        if self.toggle.is_empty() {
+            "None"
+        } else {
+            &self.toggle
+        }
     }
 
     /// Renders common filters.
@@ -276,6 +357,21 @@ impl Common {
         }
     }
 
+    /// Renders common sound options.
+    pub fn render_sound(&mut self, ui: &Ui) {
+        if self.sound.is_some() {
+            if ui.checkbox("Enabled", &mut true) {
+                self.sound = None;
+            }
+        } else if ui.checkbox("Enabled", &mut false) {
+            self.sound = Some(SoundCue::default());
+        }
+
+        if let Some(sound) = &mut self.sound {
+            sound.render_options(ui);
+        }
+    }
+
     /// Renders common debug information.
     pub fn render_debug(&mut self, ui: &Ui, ctx: &RenderCtx) {
         ui.text(format!("Id: {}", self.id));
@@ -292,12 +388,17 @@ impl Default for Common {
             enabled: true,
             id: ELEMENT_ID.generate(),
             name: "Unnamed".into(),
+            key: String::new(),
+            toggle: String::new(),
             anchor: ElementAnchor::default(),
             pos: [0.0, 0.0],
             opacity: 1.0,
             trigger: ProgressTrigger::default(),
             filter: FilterTrigger::default(),
             animation: None,
+            sound: None,
+            publish: false,
+            publish_state: PublishState::DEFAULT,
             dragging: false,
             resize: Resizer::default(),
         }
@@ -310,12 +411,17 @@ impl Clone for Common {
             enabled: self.enabled,
             id: ELEMENT_ID.generate(), // we want a fresh id for the clone
             name: self.name.clone(),
+            key: self.key.clone(),
+            toggle: self.toggle.clone(),
             anchor: self.anchor,
             pos: self.pos,
             opacity: self.opacity,
             trigger: self.trigger.clone(),
             filter: self.filter.clone(),
             animation: self.animation.clone(),
+            sound: self.sound.clone(),
+            publish: self.publish,
+            publish_state: PublishState::DEFAULT,
             dragging: false,
             resize: Resizer::default(),
         }
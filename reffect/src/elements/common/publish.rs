@@ -0,0 +1,109 @@
+use const_default::ConstDefault;
+use std::ffi::c_char;
+
+/// Published element state.
+#[derive(Debug, Default, ConstDefault, Clone, Copy, PartialEq, Eq)]
+pub struct ElementState {
+    /// Whether the element is visible.
+    pub visible: bool,
+
+    /// Bitmask of active conditions by index.
+    pub conditions: u32,
+}
+
+/// Payload of element state Nexus events.
+///
+/// Memory layout is part of the public event interface.
+/// Pointers are only valid for the duration of the event callback.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct ElementStateEvent {
+    /// Null-terminated UTF-8 pack name.
+    pub pack: *const c_char,
+
+    /// Null-terminated UTF-8 element name.
+    pub element: *const c_char,
+
+    /// Null-terminated UTF-8 trigger source name.
+    pub source: *const c_char,
+
+    /// Whether the element is visible.
+    pub visible: bool,
+
+    /// Bitmask of active conditions by index.
+    pub conditions: u32,
+}
+
+impl ElementStateEvent {
+    /// Nexus event identifier for element state changes.
+    pub const ID: &str = "REFFECT_ELEMENT_STATE";
+}
+
+/// Memoized publishing state of an element.
+#[derive(Debug, Default, ConstDefault, Clone)]
+pub struct PublishState {
+    /// Last published state.
+    published: ElementState,
+
+    /// Timestamp of the last publish.
+    last: Option<u32>,
+
+    /// Whether a state change is waiting for the rate limit.
+    pending: bool,
+}
+
+impl PublishState {
+    /// Minimum time between publishes in milliseconds.
+    pub const MIN_INTERVAL: u32 = 250;
+
+    /// Whether a state change is waiting for the rate limit.
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+
+    /// Updates with the current state, returning the state to publish.
+    pub fn update(&mut self, state: ElementState, now: u32) -> Option<ElementState> {
+        if state == self.published {
+            self.pending = false;
+            None
+        } else if self
+            .last
+            .is_none_or(|last| now.saturating_sub(last) >= Self::MIN_INTERVAL)
+        {
+            self.published = state;
+            self.last = Some(now);
+            self.pending = false;
+            Some(state)
+        } else {
+            self.pending = true;
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limit() {
+        let visible = ElementState {
+            visible: true,
+            conditions: 0,
+        };
+        let hidden = ElementState::default();
+        let mut state = PublishState::default();
+
+        assert_eq!(state.update(hidden, 0), None);
+        assert_eq!(state.update(visible, 100), Some(visible));
+        assert_eq!(state.update(hidden, 200), None);
+        assert!(state.is_pending());
+        assert_eq!(state.update(hidden, 350), Some(hidden));
+        assert!(!state.is_pending());
+
+        // flicker within the interval is dropped
+        assert_eq!(state.update(visible, 400), None);
+        assert_eq!(state.update(hidden, 500), None);
+        assert!(!state.is_pending());
+    }
+}
@@ -1,7 +1,7 @@
 use super::Element;
 use crate::{
     context::Context,
-    elements::{Bar, Common, Group, IconElement, IconList, RenderCtx, Text},
+    elements::{Bar, Common, Group, IconElement, IconList, RenderCtx, Repeater, Text},
     enums::check_variant_array,
     render::{Bounds, Rect},
     tree::TreeNode,
@@ -33,6 +33,9 @@ pub enum ElementType {
 
     /// Progress bar element.
     Bar(Bar), // TODO: box for reduced size?
+
+    /// Repeater element.
+    Repeater(Repeater),
 }
 
 impl VariantArray for ElementType {
@@ -42,6 +45,7 @@ impl VariantArray for ElementType {
         Self::IconList(IconList::DEFAULT),
         Self::Text(Text::DEFAULT),
         Self::Bar(Bar::DEFAULT),
+        Self::Repeater(Repeater::DEFAULT),
     ];
 }
 
@@ -50,7 +54,17 @@ const _: () = check_variant_array::<ElementType>();
 impl ElementType {
     /// Whether the elements is a passthrough ignoring trigger visibility.
     pub const fn is_passthrough(&self) -> bool {
-        matches!(self, Self::Group(_) | Self::IconList(_))
+        matches!(self, Self::Group(_) | Self::IconList(_) | Self::Repeater(_))
+    }
+
+    /// Returns the bitmask of active conditions by index.
+    pub fn active_conditions(&self) -> u32 {
+        match self {
+            Self::Icon(element) => element.icon.props.matched(),
+            Self::Text(text) => text.props.matched(),
+            Self::Bar(bar) => bar.props.matched(),
+            Self::Group(_) | Self::IconList(_) | Self::Repeater(_) => 0,
+        }
     }
 
     /// Renders the element.
@@ -61,18 +75,21 @@ impl ElementType {
             Self::IconList(list) => list.render(ui, ctx, common),
             Self::Text(text) => text.render(ui, ctx, common),
             Self::Bar(bar) => bar.render(ui, ctx, common),
+            Self::Repeater(repeater) => repeater.render(ui, ctx, common),
         }
     }
 
     /// Renders element options.
-    pub fn render_options(&mut self, ui: &Ui, ctx: &RenderCtx) {
+    pub fn render_options(&mut self, ui: &Ui, ctx: &RenderCtx) -> bool {
         match self {
             Self::Group(group) => group.render_options(ui, ctx),
             Self::Icon(icon) => icon.render_options(ui, ctx),
-            Self::IconList(list) => list.render_options(ui, ctx),
+            Self::IconList(list) => return list.render_options(ui, ctx),
             Self::Text(text) => text.render_options(ui, ctx),
             Self::Bar(bar) => bar.render_options(ui, ctx),
+            Self::Repeater(repeater) => repeater.render_options(ui, ctx),
         }
+        false
     }
 
     /// Renders element tabs.
@@ -83,6 +100,7 @@ impl ElementType {
             Self::IconList(list) => list.render_tabs(ui, ctx, common),
             Self::Text(text) => text.render_tabs(ui, ctx, common),
             Self::Bar(bar) => bar.render_tabs(ui, ctx, common),
+            Self::Repeater(repeater) => repeater.render_tabs(ui, ctx),
         }
     }
 
@@ -90,7 +108,7 @@ impl ElementType {
     pub fn render_filters(&mut self, ui: &Ui, ctx: &Context) {
         match self {
             Self::IconList(list) => list.render_filters(ui, ctx),
-            Self::Group(_) | Self::Icon(_) | Self::Text(_) | Self::Bar(_) => {}
+            Self::Group(_) | Self::Icon(_) | Self::Text(_) | Self::Bar(_) | Self::Repeater(_) => {}
         }
     }
 
@@ -102,6 +120,7 @@ impl ElementType {
             Self::IconList(list) => list.render_debug(ui, ctx),
             Self::Text(text) => text.render_debug(ui, ctx),
             Self::Bar(bar) => bar.render_debug(ui, ctx),
+            Self::Repeater(repeater) => repeater.render_debug(ui, ctx),
         }
     }
 
@@ -144,6 +163,14 @@ impl ElementType {
             _ => None,
         }
     }
+
+    /// Attempts to retrieve the inner repeater element.
+    pub fn as_repeater(&self) -> Option<&Repeater> {
+        match self {
+            Self::Repeater(repeater) => Some(repeater),
+            _ => None,
+        }
+    }
 }
 
 impl TreeNode for ElementType {
@@ -154,6 +181,7 @@ impl TreeNode for ElementType {
             Self::IconList(list) => list.children(),
             Self::Text(text) => text.children(),
             Self::Bar(bar) => bar.children(),
+            Self::Repeater(repeater) => repeater.children(),
         }
     }
 }
@@ -166,6 +194,7 @@ impl Bounds for ElementType {
             Self::IconList(list) => list.bounds(ui, ctx),
             Self::Text(text) => text.bounds(ui, ctx),
             Self::Bar(bar) => bar.bounds(ui, ctx),
+            Self::Repeater(repeater) => repeater.bounds(ui, ctx),
         }
     }
 }
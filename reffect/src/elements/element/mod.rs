@@ -77,8 +77,9 @@ impl Element {
         let mut open_delete = false;
         let mut open_resize = false;
 
+        let mut added = false;
         item_context_menu(&id, || {
-            self.common.render_context_menu(ui, children);
+            added = self.common.render_context_menu(ui, children);
 
             if MenuItem::new("Cut").build(ui) {
                 action = ElementAction::Cut;
@@ -100,6 +101,9 @@ impl Element {
             let _color = ui.push_style_color(StyleColor::HeaderHovered, colors::DELETE_HOVER);
             open_delete = MenuItem::new("Delete").build(ui);
         });
+        if added {
+            state.set_tree_changed();
+        }
 
         if let Some(resizer) = self.common.render_resize(ui, open_resize) {
             resizer.resize_element(self);
@@ -130,28 +134,31 @@ impl Element {
     }
 
     /// Attempts to render options if selected.
-    /// Returns `true` if the element or a child rendered.
-    pub fn try_render_options(&mut self, ui: &Ui, ctx: &RenderCtx) -> bool {
+    ///
+    /// Returns whether references may have changed if the element or a child rendered.
+    pub fn try_render_options(&mut self, ui: &Ui, ctx: &RenderCtx) -> Option<bool> {
         let id = self.common.id;
         if ctx.edit.is_selected(id) {
-            self.render_options(ui, ctx);
-            return true;
+            return Some(self.render_options(ui, ctx));
         } else if let (true, Some(children)) = (ctx.edit.is_selected_parent(id), self.children()) {
             for child in children {
-                if child.try_render_options(ui, ctx) {
-                    return true;
+                if let Some(changed) = child.try_render_options(ui, ctx) {
+                    return Some(changed);
                 }
             }
         }
-        false
+        None
     }
 
     /// Renders element options.
-    pub fn render_options(&mut self, ui: &Ui, ctx: &RenderCtx) {
+    ///
+    /// Returns `true` if references may have changed.
+    pub fn render_options(&mut self, ui: &Ui, ctx: &RenderCtx) -> bool {
+        let mut changed = false;
         if let Some(_token) = ui.tab_item(format!("{}###type", self.kind.as_ref())) {
-            self.common.render_options(ui, ctx);
+            changed |= self.common.render_options(ui, ctx);
             ui.spacing();
-            self.kind.render_options(ui, ctx);
+            changed |= self.kind.render_options(ui, ctx);
         }
 
         self.kind.render_tabs(ui, ctx, &self.common);
@@ -163,10 +170,15 @@ impl Element {
         if let Some(_token) = ui.tab_item("Animation") {
             self.common.render_animation(ui);
         }
+        if let Some(_token) = ui.tab_item("Sound") {
+            self.common.render_sound(ui);
+        }
         if let Some(_token) = ui.tab_item("?") {
             self.common.render_debug(ui, ctx);
             self.kind.render_debug(ui, ctx, &self.common);
         }
+
+        changed
     }
 }
 
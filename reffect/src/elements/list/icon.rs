@@ -81,18 +81,23 @@ impl ListIcon {
     }
 
     /// Renders icon element options.
-    pub fn render_options(&mut self, ui: &Ui, ctx: &RenderCtx) -> DynAction<Self> {
+    ///
+    /// Returns whether the trigger changed and the action to apply to all icons.
+    pub fn render_options(&mut self, ui: &Ui, ctx: &RenderCtx) -> (bool, DynAction<Self>) {
         ui.checkbox("Enabled", &mut self.enabled);
         ui.input_text("Name", &mut self.name).build();
 
         ui.spacing();
 
-        self.trigger.render_options(ui, ctx);
+        let changed = self.trigger.render_options(ui, ctx);
 
         ui.spacing();
 
         let icon_action = self.icon.render_options(ui, ctx);
-        icon_action.map(|list_icon: &mut Self| &mut list_icon.icon)
+        (
+            changed,
+            icon_action.map(|list_icon: &mut Self| &mut list_icon.icon),
+        )
     }
 
     /// Renders icon element debug information.
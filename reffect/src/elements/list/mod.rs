@@ -94,7 +94,11 @@ impl IconList {
     }
 
     /// Renders icon list options.
-    pub fn render_options(&mut self, ui: &Ui, ctx: &RenderCtx) {
+    ///
+    /// Returns `true` if icons were added or an icon trigger changed.
+    pub fn render_options(&mut self, ui: &Ui, ctx: &RenderCtx) -> bool {
+        let mut changed = false;
+
         enum_combo(ui, "Layout", &mut self.layout, ComboBoxFlags::empty());
 
         enum_combo(ui, "Direction", &mut self.direction, ComboBoxFlags::empty());
@@ -180,7 +184,9 @@ impl IconList {
 
             drop(style);
             if open {
-                copy_action.or(list_icon.render_options(ui, ctx));
+                let (trigger_changed, icon_action) = list_icon.render_options(ui, ctx);
+                changed |= trigger_changed;
+                copy_action.or(icon_action);
                 ui.spacing();
             }
         }
@@ -196,8 +202,11 @@ impl IconList {
             }
         });
 
+        changed |= matches!(action, IconAction::Paste(_));
         action.perform(&mut self.icons, self.size);
         copy_action.apply_to_all(&mut self.icons);
+
+        changed
     }
 
     /// Renders icon list tabs.
@@ -2,6 +2,7 @@ pub mod align;
 pub mod bar;
 pub mod icon;
 pub mod list;
+pub mod repeater;
 pub mod text;
 
 mod anchor;
@@ -14,6 +15,7 @@ mod group;
 mod pack;
 mod props;
 mod render_ctx;
+mod sound;
 mod unit;
 
 pub use self::{
@@ -30,6 +32,8 @@ pub use self::{
     pack::*,
     props::*,
     render_ctx::*,
+    repeater::Repeater,
+    sound::*,
     text::Text,
     unit::*,
 };
@@ -8,7 +8,7 @@ use crate::{
         Bounds, Io, delete_confirm_modal, item_context_menu, style_disabled_if, tree_select_empty,
     },
     schema::Schema,
-    tree::{FontLoader, Loader, TreeNode, VisitMut},
+    tree::{FontLoader, Loader, ReferenceBinder, ReferenceTargets, TreeNode, VisitMut},
     trigger::{FilterTrigger, MapTrigger},
 };
 use nexus::imgui::{MenuItem, StyleColor, Ui};
@@ -32,6 +32,10 @@ pub struct Pack {
 
     #[serde(skip)]
     pub file: PathBuf,
+
+    /// Reference targets.
+    #[serde(skip)]
+    pub references: ReferenceTargets,
 }
 
 impl Pack {
@@ -54,6 +58,17 @@ impl Pack {
     /// Loads the pack elements.
     pub fn load(&mut self) {
         Loader::new().visit_pack(self);
+        for target in ReferenceBinder::bind(self) {
+            log::warn!(
+                "Cyclic trigger reference to \"{target}\" in pack \"{}\"",
+                self.name()
+            );
+        }
+    }
+
+    /// Rebinds trigger references after edits.
+    pub fn bind_references(&mut self) {
+        ReferenceBinder::bind(self);
     }
 
     /// Loads or reloads fonts used in the pack.
@@ -136,8 +151,9 @@ impl Pack {
         let mut open_delete = false;
         let mut open_resize = false;
 
+        let mut added = false;
         item_context_menu(&id, || {
-            self.common.render_context_menu(ui, Some(children));
+            added = self.common.render_context_menu(ui, Some(children));
 
             open_resize = MenuItem::new("Resize").build(ui);
 
@@ -150,9 +166,16 @@ impl Pack {
             self.common.render_tree_label(ui, "Pack");
         }
 
+        if added {
+            state.set_tree_changed();
+        }
+
         if token.is_some() {
             self.common.render_tree_children(ui, state, children);
         }
+        if state.take_tree_changed() {
+            self.bind_references();
+        }
 
         if let Some(resizer) = self.common.render_resize(ui, open_resize) {
             resizer.resize_pack(self);
@@ -177,13 +200,18 @@ impl Pack {
                 reorder,
             };
         } else if ctx.edit.is_selected_parent(id) {
-            for child in &mut self.elements {
-                if child.try_render_options(ui, ctx) {
-                    return PackEditResult {
-                        rendered: true,
-                        ..Default::default()
-                    };
+            let changed = self
+                .elements
+                .iter_mut()
+                .find_map(|child| child.try_render_options(ui, ctx));
+            if let Some(changed) = changed {
+                if changed {
+                    self.bind_references();
                 }
+                return PackEditResult {
+                    rendered: true,
+                    ..Default::default()
+                };
             }
         }
         PackEditResult::default()
@@ -193,7 +221,9 @@ impl Pack {
     fn render_options(&mut self, ui: &Ui, ctx: &RenderCtx) -> bool {
         let mut changed = false;
         if let Some(_token) = ui.tab_item("Pack###type") {
-            self.common.render_options(ui, ctx);
+            if self.common.render_options(ui, ctx) {
+                self.bind_references();
+            }
 
             ui.spacing();
 
@@ -250,6 +280,7 @@ impl Default for Pack {
             layer: 0,
             elements: Vec::new(),
             file: PathBuf::new(),
+            references: ReferenceTargets::default(),
         }
     }
 }
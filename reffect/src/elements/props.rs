@@ -2,11 +2,16 @@ use crate::{
     action::{Action, DynAction},
     colors,
     context::{Context, Updateable},
-    render::{collapsing_header_same_line_end, delete_confirm_modal, item_context_menu},
-    trigger::{Condition, ProgressActive, ProgressSource},
+    render::{
+        collapsing_header_same_line_end, delete_confirm_modal, enum_combo, helper,
+        item_context_menu, style_disabled_if,
+    },
+    trigger::{Condition, ConditionMode, ProgressActive, ProgressSource},
 };
 use const_default::ConstDefault;
-use nexus::imgui::{CollapsingHeader, Direction, MenuItem, StyleColor, TreeNodeFlags, Ui};
+use nexus::imgui::{
+    CollapsingHeader, ComboBoxFlags, Direction, MenuItem, StyleColor, TreeNodeFlags, Ui,
+};
 use partial::IntoPartial;
 use serde::{Deserialize, Serialize};
 use std::{fmt, ops};
@@ -29,10 +34,17 @@ where
     #[serde(flatten)]
     pub base: T,
 
+    #[serde(rename = "condition_mode")]
+    pub mode: ConditionMode,
+
     pub conditions: Vec<Condition<T>>,
 
     #[serde(skip)]
     pub current: T,
+
+    /// Bitmask of active conditions by index.
+    #[serde(skip)]
+    matched: u32,
 }
 
 impl<T> Props<T>
@@ -43,13 +55,39 @@ where
     /// Update the current properties using the conditions.
     pub fn update(&mut self, ctx: &Context, active: Option<&ProgressActive>, force: bool) {
         self.current = self.base.clone();
+        self.matched = 0;
         if let Some(active) = active {
-            for condition in &mut self.conditions {
+            let mut stopped = false;
+            for (i, condition) in self.conditions.iter_mut().enumerate() {
                 condition.trigger.update_if_force_or_need(ctx, force);
-                condition.process(&mut self.current, ctx, active);
+                if stopped {
+                    // skipped conditions tracking changes still need to observe
+                    condition.observe(ctx, active);
+                } else if condition.process(&mut self.current, ctx, active) {
+                    self.matched |= 1u32.checked_shl(i as u32).unwrap_or(0);
+                    stopped = self.mode.stops_at_match();
+                }
+            }
+        } else {
+            // conditions tracking changes need to observe the loss of progress
+            let inactive = ProgressActive::empty();
+            for condition in &mut self.conditions {
+                condition.observe(ctx, &inactive);
             }
         }
     }
+
+    /// Returns the bitmask of active conditions by index.
+    pub fn matched(&self) -> u32 {
+        self.matched
+    }
+
+    /// Checks whether any condition needs updates to expire.
+    pub fn is_pending(&self, ctx: &Context) -> bool {
+        self.conditions
+            .iter()
+            .any(|condition| condition.trigger.is_pending(ctx))
+    }
 }
 
 impl<T> ops::Deref for Props<T>
@@ -79,13 +117,20 @@ where
         let mut copy_action = DynAction::<Self>::empty();
         let mut action = Action::new();
 
+        enum_combo(ui, "Evaluation", &mut self.mode, ComboBoxFlags::empty());
+        helper(ui, || {
+            ui.text("All matching: apply every active condition in order");
+            ui.text("First match: apply only the first active condition");
+        });
+
         let len = self.conditions.len();
         for (i, condition) in self.conditions.iter_mut().enumerate() {
             let _id = ui.push_id(i as i32);
 
             let mut remains = true;
 
-            let label = format!("{}###cond{i}", condition.trigger);
+            let style = style_disabled_if(ui, !condition.enabled);
+            let label = format!("{condition}###cond{i}");
             let open = CollapsingHeader::new(&label)
                 .flags(TreeNodeFlags::ALLOW_ITEM_OVERLAP)
                 .begin_with_close_button(ui, &mut remains);
@@ -112,8 +157,14 @@ where
             {
                 let size_x = ui.frame_height();
                 let [spacing_x, _] = ui.clone_style().item_spacing;
-                collapsing_header_same_line_end(ui, 3.0 * size_x + 2.0 * spacing_x);
+                collapsing_header_same_line_end(ui, 4.0 * size_x + 3.0 * spacing_x);
 
+                ui.checkbox("##enabled", &mut condition.enabled);
+                if ui.is_item_hovered() {
+                    ui.tooltip_text("Enabled");
+                }
+
+                ui.same_line();
                 let _style = ui.push_style_color(StyleColor::Button, colors::TRANSPARENT);
                 if ui.arrow_button("up", Direction::Up) {
                     action = Action::Up(i);
@@ -130,11 +181,13 @@ where
                 ui.open_popup(&title);
             }
             if delete_confirm_modal(ui, &title, || {
-                ui.text(format!("Delete Condition {}?", condition.trigger))
+                ui.text(format!("Delete Condition {condition}?"))
             }) {
                 action = Action::Delete(i);
             }
 
+            drop(style);
+
             if open {
                 condition.render_options(ui, ctx, source, &self.base);
             }
@@ -157,3 +210,131 @@ where
     /// Renders partial prop options.
     fn render_options(&mut self, ui: &Ui, base: &T);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elements::text::TextProps,
+        trigger::{ChangeTrigger, ConditionTrigger, Edge, ProgressThreshold, ThresholdType},
+    };
+    use partial::{Partial, PartialOps};
+
+    fn create_condition(scale: f32) -> Condition<TextProps> {
+        let mut properties = Partial::<TextProps>::empty();
+        properties.scale = Some(scale);
+        Condition {
+            trigger: ConditionTrigger::ProgressThreshold(ProgressThreshold {
+                threshold_type: ThresholdType::Always,
+                ..ProgressThreshold::DEFAULT
+            }),
+            properties,
+            ..Condition::default()
+        }
+    }
+
+    #[test]
+    fn mode() {
+        let ctx = Context::disabled();
+        let active = ProgressActive::empty();
+        let mut props = Props::<TextProps> {
+            conditions: vec![create_condition(2.0), create_condition(3.0)],
+            ..Props::default()
+        };
+
+        props.update(&ctx, Some(&active), true);
+        assert_eq!(props.scale, 3.0);
+        assert_eq!(props.matched(), 0b11);
+
+        props.mode = ConditionMode::First;
+        props.update(&ctx, Some(&active), true);
+        assert_eq!(props.scale, 2.0);
+        assert_eq!(props.matched(), 0b01);
+
+        props.conditions[0].enabled = false;
+        props.update(&ctx, Some(&active), true);
+        assert_eq!(props.scale, 3.0);
+        assert_eq!(props.matched(), 0b10);
+    }
+
+    #[test]
+    fn first_match_beyond_mask() {
+        let ctx = Context::disabled();
+        let active = ProgressActive::empty();
+        let mut conditions = vec![create_condition(1.0); 33];
+        conditions[32] = create_condition(2.0);
+        conditions.push(create_condition(3.0));
+        for condition in &mut conditions[..32] {
+            condition.enabled = false;
+        }
+        let mut props = Props::<TextProps> {
+            mode: ConditionMode::First,
+            conditions,
+            ..Props::default()
+        };
+
+        props.update(&ctx, Some(&active), true);
+        assert_eq!(props.scale, 2.0);
+        assert_eq!(props.matched(), 0);
+    }
+
+    #[test]
+    fn change_loss() {
+        let mut ctx = Context::disabled();
+        let present = ProgressActive::always();
+        let mut change = ChangeTrigger::DEFAULT;
+        change.edges = Edge::Loss.into();
+        let mut props = Props::<TextProps> {
+            conditions: vec![Condition {
+                trigger: ConditionTrigger::RecentlyChanged(change),
+                ..create_condition(2.0)
+            }],
+            ..Props::default()
+        };
+
+        props.update(&ctx, Some(&present), true);
+        assert_eq!(props.matched(), 0);
+
+        ctx.now = 100;
+        props.update(&ctx, None, true);
+
+        ctx.now = 200;
+        props.update(&ctx, Some(&present), true);
+        assert_eq!(props.matched(), 1);
+        assert_eq!(props.scale, 2.0);
+    }
+
+    #[test]
+    fn first_match_observe() {
+        let mut ctx = Context::disabled();
+        let present = ProgressActive::always();
+        let empty = ProgressActive::empty();
+        let mut change = ChangeTrigger::DEFAULT;
+        change.edges = Edge::Loss.into();
+        let mut props = Props::<TextProps> {
+            mode: ConditionMode::First,
+            conditions: vec![
+                create_condition(2.0),
+                Condition {
+                    trigger: ConditionTrigger::RecentlyChanged(change),
+                    ..create_condition(3.0)
+                },
+            ],
+            ..Props::default()
+        };
+
+        props.update(&ctx, Some(&present), true);
+        assert_eq!(props.matched(), 0b01);
+
+        // loss happens while the first condition matches
+        ctx.now = 100;
+        props.update(&ctx, Some(&empty), true);
+        assert_eq!(props.matched(), 0b01);
+
+        ctx.now = 200;
+        props.conditions[0].enabled = false;
+        props.update(&ctx, Some(&empty), true);
+        assert_eq!(props.matched(), 0b10);
+        assert_eq!(props.scale, 3.0);
+    }
+}
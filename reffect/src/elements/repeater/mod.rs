@@ -0,0 +1,270 @@
+use super::{Direction, Element, RenderCtx};
+use crate::{
+    context::{Context, GroupMember},
+    elements::Common,
+    math::ComponentWise,
+    render::{Bounds, Rect, enum_combo, helper, input_float_with_format, input_size},
+    tree::{Loader, MemberBinder, TreeNode, VisitMut},
+};
+use const_default::ConstDefault;
+use nexus::imgui::{ComboBoxFlags, InputTextFlags, Ui};
+use serde::{Deserialize, Serialize};
+
+/// Repeater element.
+///
+/// Renders its child elements once per present group member.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct Repeater {
+    /// Repeat direction.
+    pub direction: Direction,
+
+    /// Size of a single repetition.
+    pub size: [f32; 2],
+
+    /// Padding between repetitions.
+    pub pad: f32,
+
+    /// Whether to only repeat for members of the own party or squad subgroup.
+    pub party_only: bool,
+
+    /// Template elements repeated for each member.
+    pub members: Vec<Element>,
+
+    /// Instantiated elements, indexed by group member.
+    #[serde(skip)]
+    instances: Vec<Vec<Element>>,
+}
+
+impl TreeNode for Repeater {
+    fn children(&mut self) -> Option<&mut Vec<Element>> {
+        Some(&mut self.members)
+    }
+}
+
+impl Repeater {
+    /// Returns the present group members to repeat for.
+    fn present<'ctx>(
+        &self,
+        ctx: &'ctx Context,
+    ) -> impl Iterator<Item = (usize, &'ctx GroupMember)> {
+        let party_only = self.party_only;
+        ctx.group.as_ref().ok().into_iter().flat_map(move |group| {
+            group.members.iter().enumerate().filter(move |(_, member)| {
+                member.is_present() && (!party_only || group.is_party_member(member))
+            })
+        })
+    }
+
+    /// Returns the mutable instantiated elements.
+    pub fn instances_mut(&mut self) -> &mut [Vec<Element>] {
+        &mut self.instances
+    }
+
+    /// Discards all instantiated elements.
+    pub fn clear_instances(&mut self) {
+        self.instances.clear();
+    }
+
+    /// Instantiates elements for the given number of group members.
+    ///
+    /// Returns the number of previously existing instances.
+    pub fn sync_instances(&mut self, count: usize) -> usize {
+        let prev = self.instances.len();
+        self.instances.truncate(count);
+        for member in prev..count {
+            let mut elements = self.members.clone();
+            Loader::new().visit_elements(&mut elements);
+            MemberBinder::new(member).visit_elements(&mut elements);
+            self.instances.push(elements);
+        }
+        prev.min(count)
+    }
+
+    /// Renders the repeater.
+    pub fn render(&mut self, ui: &Ui, ctx: &RenderCtx, common: &Common) {
+        let present = self.present(ctx).map(|(i, _)| i).collect::<Vec<_>>();
+        let len = present.len();
+        if ctx.edit.is_edited_or_parent(common.id) {
+            // preview template at the first member position, edit indicators only apply once
+            let offset = self
+                .direction
+                .list_item_offset(self.size, self.pad, 0, len.max(1));
+            let _token = ctx.push_offset(offset);
+            for member in &mut self.members {
+                member.render(ui, ctx);
+            }
+        } else {
+            for (i, member) in present.into_iter().enumerate() {
+                if let Some(elements) = self.instances.get_mut(member) {
+                    let offset = self.direction.list_item_offset(self.size, self.pad, i, len);
+                    let _token = ctx.push_offset(offset);
+                    for element in elements {
+                        element.render(ui, ctx);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Renders repeater options.
+    pub fn render_options(&mut self, ui: &Ui, _ctx: &RenderCtx) {
+        enum_combo(ui, "Direction", &mut self.direction, ComboBoxFlags::empty());
+
+        input_size(&mut self.size);
+        helper(ui, || {
+            ui.text("Size of a single member used for positioning");
+            ui.text("Use combatant Repeater Member to show information for the member");
+        });
+
+        input_float_with_format(
+            "Spacing",
+            &mut self.pad,
+            1.0,
+            10.0,
+            "%.2f",
+            InputTextFlags::empty(),
+        );
+
+        ui.checkbox("Own party only", &mut self.party_only);
+        helper(ui, || {
+            ui.text("Only repeat for members of the own party");
+            ui.text("In a squad this is the own subgroup");
+        });
+    }
+
+    /// Renders repeater tabs.
+    pub fn render_tabs(&mut self, _ui: &Ui, _ctx: &RenderCtx) {}
+
+    /// Renders repeater debug information.
+    pub fn render_debug(&mut self, ui: &Ui, ctx: &RenderCtx) {
+        ui.text(format!("Members: {}", self.members.len()));
+        ui.text(format!("Instances: {}", self.instances.len()));
+        ui.text(format!("Present: {}", self.present(ctx).count()));
+    }
+}
+
+impl Bounds for Repeater {
+    fn bounds(&self, ui: &Ui, ctx: &Context) -> Rect {
+        let (min, max) = Bounds::combined_bounds(&self.members, ui, ctx);
+        let len = self.present(ctx).count().max(1);
+        let first = self.direction.list_item_offset(self.size, self.pad, 0, len);
+        let last = self
+            .direction
+            .list_item_offset(self.size, self.pad, len - 1, len);
+        (
+            min.add(first.component_wise_zip(&last, f32::min)),
+            max.add(first.component_wise_zip(&last, f32::max)),
+        )
+    }
+}
+
+impl Clone for Repeater {
+    fn clone(&self) -> Self {
+        Self {
+            direction: self.direction,
+            size: self.size,
+            pad: self.pad,
+            party_only: self.party_only,
+            members: self.members.clone(),
+            instances: Vec::new(), // dont clone the instances
+        }
+    }
+}
+
+impl ConstDefault for Repeater {
+    const DEFAULT: Self = Self {
+        direction: Direction::Down,
+        size: [100.0, 20.0],
+        pad: 2.0,
+        party_only: false,
+        members: Vec::new(),
+        instances: Vec::new(),
+    };
+}
+
+impl Default for Repeater {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elements::{ElementType, Group},
+        trigger::{ProgressSource, ProgressTrigger},
+    };
+
+    fn create_repeater() -> Repeater {
+        let child = Element {
+            common: Common {
+                trigger: ProgressTrigger::with(ProgressSource::Always),
+                ..Common::default()
+            },
+            ..Element::default()
+        };
+        Repeater {
+            members: vec![Element {
+                kind: ElementType::Group(Group {
+                    members: vec![child],
+                }),
+                ..Element::default()
+            }],
+            ..Repeater::default()
+        }
+    }
+
+    fn bound_members(repeater: &mut Repeater) -> Vec<(Option<usize>, Option<usize>)> {
+        repeater
+            .instances_mut()
+            .iter()
+            .map(|elements| {
+                let element = &elements[0];
+                let child = match &element.kind {
+                    ElementType::Group(group) => group.members[0].common.trigger.member(),
+                    _ => unreachable!(),
+                };
+                (element.common.trigger.member(), child)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sync_instances() {
+        let mut repeater = create_repeater();
+
+        assert_eq!(repeater.sync_instances(2), 0);
+        assert_eq!(repeater.instances_mut().len(), 2);
+
+        assert_eq!(repeater.sync_instances(5), 2);
+        assert_eq!(repeater.instances_mut().len(), 5);
+
+        assert_eq!(repeater.sync_instances(1), 1);
+        assert_eq!(repeater.instances_mut().len(), 1);
+
+        assert_eq!(repeater.sync_instances(0), 0);
+        assert!(repeater.instances_mut().is_empty());
+    }
+
+    #[test]
+    fn member_binding() {
+        let mut repeater = create_repeater();
+        repeater.sync_instances(3);
+        assert_eq!(
+            bound_members(&mut repeater),
+            [(Some(0), Some(0)), (Some(1), Some(1)), (Some(2), Some(2))]
+        );
+        assert_eq!(repeater.members[0].common.trigger.member(), None);
+
+        // shrinking and growing again rebinds new instances
+        repeater.sync_instances(1);
+        repeater.sync_instances(2);
+        assert_eq!(
+            bound_members(&mut repeater),
+            [(Some(0), Some(0)), (Some(1), Some(1))]
+        );
+    }
+}
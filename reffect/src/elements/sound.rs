@@ -0,0 +1,193 @@
+use super::ElementState;
+use crate::{
+    audio::{Sound, SoundPlay},
+    colors::{Color, Colored},
+    named::Named,
+    render::{enum_combo_bitflags, helper, input_seconds, slider_percent},
+    serde::bitflags,
+};
+use enumflags2::{BitFlags, bitflags};
+use nexus::imgui::{ComboBoxFlags, Ui};
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumIter, IntoStaticStr, VariantArray};
+
+/// Element sound cue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct SoundCue {
+    /// Sound to play.
+    pub sound: Sound,
+
+    /// Events playing the sound.
+    #[serde(with = "bitflags")]
+    #[cfg_attr(feature = "schema", schemars(with = "bitflags::Schema<CueEvent>"))]
+    pub events: BitFlags<CueEvent>,
+
+    /// Volume between `0.0` and `1.0`.
+    pub volume: f32,
+
+    /// Minimum time between plays in milliseconds.
+    pub cooldown: u32,
+
+    /// Memoized previous element state.
+    #[serde(skip)]
+    prev: Option<ElementState>,
+
+    /// Memoized timestamp of last play.
+    #[serde(skip)]
+    last: Option<u32>,
+}
+
+impl SoundCue {
+    /// Updates with the current element state, returning the sound to play.
+    pub fn update(&mut self, state: ElementState, now: u32) -> Option<SoundPlay> {
+        let prev = self.prev.replace(state)?;
+        let activated = self.events.contains(CueEvent::Trigger) && state.visible && !prev.visible;
+        let matched = self.events.contains(CueEvent::Condition)
+            && state.visible
+            && state.conditions & !prev.conditions != 0;
+        let ready = self
+            .last
+            .is_none_or(|last| now.saturating_sub(last) >= self.cooldown);
+
+        if (activated || matched) && ready {
+            self.last = Some(now);
+            Some(SoundPlay {
+                sound: self.sound.clone(),
+                volume: self.volume,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Renders sound cue options.
+    pub fn render_options(&mut self, ui: &Ui) {
+        self.sound.render_select(ui);
+
+        enum_combo_bitflags(ui, "Play on", &mut self.events, ComboBoxFlags::empty());
+        helper(ui, || {
+            ui.text("Trigger: element trigger became active");
+            ui.text("Condition: a condition started matching");
+        });
+
+        slider_percent(ui, "Volume", &mut self.volume);
+
+        input_seconds(ui, "Cooldown", &mut self.cooldown);
+        helper(ui, || ui.text("Minimum time between plays"));
+    }
+}
+
+impl Default for SoundCue {
+    fn default() -> Self {
+        Self {
+            sound: Sound::default(),
+            events: CueEvent::Trigger.into(),
+            volume: 1.0,
+            cooldown: 1000,
+            prev: None,
+            last: None,
+        }
+    }
+}
+
+/// Event playing a sound cue.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    AsRefStr,
+    IntoStaticStr,
+    Display,
+    EnumIter,
+    VariantArray,
+    Serialize,
+    Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[bitflags]
+#[repr(u8)]
+pub enum CueEvent {
+    /// Element trigger became active.
+    Trigger = 1 << 0,
+
+    /// Condition started matching.
+    Condition = 1 << 1,
+}
+
+impl Named for CueEvent {
+    fn name(&self) -> &'static str {
+        self.into()
+    }
+
+    fn short_name(&self) -> &'static str {
+        self.name()
+    }
+}
+
+impl Colored for CueEvent {
+    fn colored(&self) -> Option<Color> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::{RecordingAudio, play_sounds};
+
+    const HIDDEN: ElementState = ElementState {
+        visible: false,
+        conditions: 0,
+    };
+
+    const VISIBLE: ElementState = ElementState {
+        visible: true,
+        conditions: 0,
+    };
+
+    const MATCHED: ElementState = ElementState {
+        visible: true,
+        conditions: 0b10,
+    };
+
+    #[test]
+    fn edges_and_cooldown() {
+        let mut cue = SoundCue {
+            events: CueEvent::Trigger | CueEvent::Condition,
+            volume: 0.5,
+            ..SoundCue::default()
+        };
+        let mut audio = RecordingAudio::default();
+        let mut step = |state, now| play_sounds(&mut audio, cue.update(state, now), false);
+
+        step(VISIBLE, 0); // initial state does not play
+        step(HIDDEN, 100);
+        step(VISIBLE, 200); // plays
+        step(MATCHED, 300); // on cooldown
+        step(HIDDEN, 1500);
+        step(MATCHED, 1600); // plays
+        step(MATCHED, 2800); // no change
+
+        let expected = SoundPlay {
+            sound: Sound::default(),
+            volume: 0.5,
+        };
+        assert_eq!(audio.played, [expected.clone(), expected]);
+    }
+
+    #[test]
+    fn muted() {
+        let mut cue = SoundCue::default();
+        let mut audio = RecordingAudio::default();
+        play_sounds(&mut audio, cue.update(HIDDEN, 0), true);
+        play_sounds(&mut audio, cue.update(VISIBLE, 100), true);
+        assert!(audio.played.is_empty());
+    }
+}
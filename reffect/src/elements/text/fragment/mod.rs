@@ -17,6 +17,9 @@ pub enum TextFragment<'s> {
     /// Element name.
     Name,
 
+    /// Account name of the bound group member.
+    Account,
+
     /// Progress intensity.
     Intensity { pretty: bool },
 
@@ -38,10 +41,12 @@ impl<'s> TextFragment<'s> {
         ctx: &Context,
         settings: &FormatSettings,
         name: &str,
+        member: Option<usize>,
     ) -> impl fmt::Display {
-        fmt::from_fn(|formatter| match *self {
+        fmt::from_fn(move |formatter| match *self {
             Self::Literal(text) => formatter.write_str(text),
             Self::Name => formatter.write_str(name),
+            Self::Account => formatter.write_str(Self::account(ctx, member)),
             Self::Intensity { pretty } => {
                 if pretty {
                     active.intensity().fmt(formatter)
@@ -60,4 +65,16 @@ impl<'s> TextFragment<'s> {
             }
         })
     }
+
+    /// Returns the account name of the group member.
+    fn account(ctx: &Context, member: Option<usize>) -> &str {
+        if ctx.edit.is_editing() && member.is_none() {
+            "Account.1234"
+        } else {
+            member
+                .and_then(|member| ctx.group.as_ref().ok()?.members.get(member))
+                .and_then(|member| member.account_name())
+                .unwrap_or_default()
+        }
+    }
 }
@@ -62,6 +62,10 @@ impl<'s> TextFragment<'s> {
                 iter.next();
                 Some(Self::Name)
             }
+            'a' => {
+                iter.next();
+                Some(Self::Account)
+            }
             'i' | 's' | 'I' => {
                 // backwards compat for %s stacks
                 iter.next();
@@ -154,4 +158,17 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn parse_account() {
+        let fragments = TextFragment::parse("%a: %n").collect::<Vec<_>>();
+        assert_eq!(
+            fragments,
+            [
+                TextFragment::Account,
+                TextFragment::Literal(": "),
+                TextFragment::Name
+            ]
+        );
+    }
 }
@@ -78,7 +78,7 @@ impl Text {
                 let _ = write!(
                     &mut text,
                     "{}",
-                    fragment.display(active, ctx, settings, &common.name)
+                    fragment.display(active, ctx, settings, &common.name, common.trigger.member())
                 );
             }
             text
@@ -134,6 +134,7 @@ impl Text {
             ui.text("Uppercase for pretty format");
             ui.text("Suffix 1 or 2 for primary/secondary");
             ui.text("%n for name");
+            ui.text("%a for repeater member account name");
             ui.text("%i for intensity");
             ui.text("%c for current amount");
             ui.text("%f for full/max amount");
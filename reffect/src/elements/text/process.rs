@@ -34,6 +34,7 @@ impl Processing {
         match fragment {
             TextFragment::Literal(_) | TextFragment::Name => Self::Static,
             TextFragment::Intensity { .. } => Self::Trigger,
+            TextFragment::Account => Self::Frame, // group changes are not tracked by the trigger
             TextFragment::Current { .. }
             | TextFragment::Full { .. }
             | TextFragment::Percent { .. } => {
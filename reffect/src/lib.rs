@@ -1,6 +1,7 @@
 mod action;
 mod addon;
 mod assets;
+mod audio;
 mod clipboard;
 mod math;
 mod render;
@@ -45,6 +45,45 @@ where
     replaced
 }
 
+/// Renders a combo selecting a name from the given options.
+///
+/// Adds a "None" entry clearing the name if optional.
+pub fn name_combo<'a>(
+    ui: &Ui,
+    label: impl AsRef<str>,
+    current: &mut String,
+    names: impl IntoIterator<Item = &'a str>,
+    optional: bool,
+) -> bool {
+    let mut changed = false;
+    let preview = if optional && current.is_empty() {
+        "None"
+    } else {
+        current.as_str()
+    };
+    if let Some(_token) = ui.begin_combo(label, preview) {
+        if optional
+            && Selectable::new("None")
+                .selected(current.is_empty())
+                .build(ui)
+        {
+            current.clear();
+            changed = true;
+        }
+        for name in names {
+            let selected = *current == name;
+            if Selectable::new(name).selected(selected).build(ui) {
+                *current = name.into();
+                changed = true;
+            }
+            if selected {
+                ui.set_item_default_focus();
+            }
+        }
+    }
+    changed
+}
+
 pub fn enum_combo_bitflags<T>(
     ui: &Ui,
     label: impl AsRef<str>,
@@ -1,5 +1,7 @@
-use super::input_text_simple_menu;
-use crate::chatlink::{decode_item, decode_skill, decode_trait};
+use super::{button_size_with_spacing, input_text_simple_menu};
+use crate::chatlink::{
+    decode_item, decode_skill, decode_trait, encode_item, encode_skill, encode_trait,
+};
 use nexus::imgui::{InputTextFlags, Ui};
 
 pub fn input_chatlink(
@@ -8,9 +10,13 @@ pub fn input_chatlink(
     id: &mut u32,
     flags: InputTextFlags,
     decode: impl FnOnce(&str) -> Option<u32>,
+    encode: impl FnOnce(u32) -> String,
 ) -> bool {
     let label = label.as_ref();
     let mut text = id.to_string(); // TODO: switch to faster int/float to string conversion libraries
+
+    // leave space for copy button
+    ui.set_next_item_width(ui.calc_item_width() - button_size_with_spacing(ui));
     let changed = ui
         .input_text(label, &mut text)
         .flags(flags | InputTextFlags::AUTO_SELECT_ALL | InputTextFlags::CALLBACK_RESIZE)
@@ -23,11 +29,22 @@ pub fn input_chatlink(
         }
     }
     input_text_simple_menu(ui, format!("##{label}ctx"), &mut text);
+
+    let [spacing, _] = ui.clone_style().item_inner_spacing;
+    ui.same_line_with_spacing(0.0, spacing);
+    let size = ui.frame_height();
+    if ui.button_with_size(format!("&##{label}copy"), [size, size]) {
+        ui.set_clipboard_text(encode(*id));
+    }
+    if ui.is_item_hovered() {
+        ui.tooltip_text("Copy chat link");
+    }
+
     changed
 }
 
 pub fn input_item_id(ui: &Ui, label: impl AsRef<str>, id: &mut u32, flags: InputTextFlags) -> bool {
-    input_chatlink(ui, label, id, flags, decode_item)
+    input_chatlink(ui, label, id, flags, decode_item, encode_item)
 }
 
 pub fn input_skill_id(
@@ -36,7 +53,7 @@ pub fn input_skill_id(
     id: &mut u32,
     flags: InputTextFlags,
 ) -> bool {
-    input_chatlink(ui, label, id, flags, decode_skill)
+    input_chatlink(ui, label, id, flags, decode_skill, encode_skill)
 }
 
 pub fn input_trait_id(
@@ -45,5 +62,5 @@ pub fn input_trait_id(
     id: &mut u32,
     flags: InputTextFlags,
 ) -> bool {
-    input_chatlink(ui, label, id, flags, decode_trait)
+    input_chatlink(ui, label, id, flags, decode_trait, encode_trait)
 }
@@ -1,5 +1,5 @@
 use super::GeneralSettings;
-use crate::context::{Context, EditSettings};
+use crate::context::{Context, CustomEvent, CustomMapGroup, CustomTimer, EditSettings, Toggles};
 use semver::Version;
 use serde::{Deserialize, Serialize};
 
@@ -11,6 +11,14 @@ pub struct ContextSettings {
 
     pub edit: EditSettings,
 
+    pub map_groups: Vec<CustomMapGroup>,
+
+    pub events: Vec<CustomEvent>,
+
+    pub toggles: Toggles,
+
+    pub timers: Vec<CustomTimer>,
+
     #[serde(skip_serializing)]
     edit_during_combat: Option<bool>,
 
@@ -23,6 +31,10 @@ impl ContextSettings {
         Self {
             general: settings.clone(),
             edit: ctx.edit.settings.clone(),
+            map_groups: ctx.map_groups.clone(),
+            events: ctx.events.clone(),
+            toggles: ctx.toggles.clone(),
+            timers: ctx.timers.clone(),
             edit_during_combat: None,
             edit_show_all: None,
         }
@@ -36,6 +48,10 @@ impl ContextSettings {
         let Self {
             general,
             mut edit,
+            map_groups,
+            events,
+            toggles,
+            timers,
             edit_during_combat,
             edit_show_all,
         } = self;
@@ -50,5 +66,10 @@ impl ContextSettings {
 
         *settings = general;
         ctx.edit.settings = edit;
+        ctx.map_groups = map_groups;
+        ctx.events = events;
+        ctx.update_events();
+        ctx.toggles = toggles;
+        ctx.timers = timers;
     }
 }
@@ -13,6 +13,7 @@ use serde::{Deserialize, Serialize};
 pub struct GeneralSettings {
     pub save_on_unload: bool,
     pub use_game_icons: bool,
+    pub mute_sounds: bool,
     pub format: FormatSettings,
     pub font: LoadedFont,
     pub icon: IconSettings,
@@ -23,6 +24,7 @@ impl GeneralSettings {
         Self {
             save_on_unload: true,
             use_game_icons: false,
+            mute_sounds: false,
             format: FormatSettings::new(),
             font: LoadedFont::empty(),
             icon: IconSettings::new(),
@@ -47,6 +49,7 @@ impl GeneralSettings {
         let Self {
             save_on_unload,
             use_game_icons,
+            mute_sounds,
             format,
             font,
             icon: _,
@@ -58,6 +61,9 @@ impl GeneralSettings {
             ui.text("Experimental, may cause memory leaks and/or crashes")
         });
 
+        ui.checkbox("Mute sounds", mute_sounds);
+        helper(ui, || ui.text("Disables sound cues of all elements"));
+
         format.render_options(ui);
 
         font.render_select(ui, "Font");
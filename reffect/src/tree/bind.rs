@@ -0,0 +1,20 @@
+use super::VisitMut;
+use crate::trigger::ProgressTrigger;
+
+/// Binds progress triggers to a group member.
+#[derive(Debug, Clone, Copy)]
+pub struct MemberBinder {
+    member: usize,
+}
+
+impl MemberBinder {
+    pub const fn new(member: usize) -> Self {
+        Self { member }
+    }
+}
+
+impl VisitMut for MemberBinder {
+    fn visit_progress_trigger(&mut self, trigger: &mut ProgressTrigger) {
+        trigger.bind_member(Some(self.member));
+    }
+}
@@ -1,10 +1,12 @@
+mod bind;
 mod font_load;
 mod load;
+mod reference;
 mod resize;
 mod update;
 mod visit;
 
-pub use self::{font_load::*, load::*, resize::*, update::*, visit::*};
+pub use self::{bind::*, font_load::*, load::*, reference::*, resize::*, update::*, visit::*};
 
 use crate::elements::Element;
 
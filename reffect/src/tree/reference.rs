@@ -0,0 +1,281 @@
+use super::VisitMut;
+use crate::{
+    elements::{Element, Pack, list::ListIcon},
+    id::Id,
+    trigger::{ProgressActive, ProgressTrigger},
+};
+use std::collections::{HashMap, HashSet};
+
+/// Trigger states of reference targets within a pack.
+#[derive(Debug, Default, Clone)]
+pub struct ReferenceTargets {
+    /// Bound target elements.
+    targets: HashSet<Id>,
+
+    /// Target states of the previous update.
+    prev: HashMap<Id, Option<ProgressActive>>,
+
+    /// Target states of the current update.
+    current: HashMap<Id, Option<ProgressActive>>,
+}
+
+impl ReferenceTargets {
+    /// Replaces the target elements, keeping states of remaining targets.
+    pub fn set_targets(&mut self, targets: HashSet<Id>) {
+        self.prev.retain(|id, _| targets.contains(id));
+        self.current.retain(|id, _| targets.contains(id));
+        self.targets = targets;
+    }
+
+    /// Checks whether the element is a reference target.
+    pub fn is_target(&self, id: Id) -> bool {
+        self.targets.contains(&id)
+    }
+
+    /// Returns the state of the target element.
+    ///
+    /// Falls back to the previous update if the target was not updated yet.
+    pub fn get(&self, id: Id) -> Option<&ProgressActive> {
+        self.current
+            .get(&id)
+            .or_else(|| self.prev.get(&id))
+            .and_then(Option::as_ref)
+    }
+
+    /// Records the state of the element if it is a reference target.
+    pub fn record(&mut self, id: Id, active: Option<&ProgressActive>) {
+        if self.is_target(id) {
+            self.current.insert(id, active.cloned());
+        }
+    }
+
+    /// Finishes the current update.
+    ///
+    /// Returns whether any target state changed and references need another update.
+    pub fn finish(&mut self) -> bool {
+        let changed = self.current != self.prev;
+        self.prev = std::mem::take(&mut self.current);
+        changed
+    }
+}
+
+/// Dependency of an element trigger on another element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Dependency {
+    Parent(Id),
+    Reference(String),
+}
+
+/// Binds reference triggers to their target elements within a pack.
+#[derive(Debug, Default)]
+pub struct ReferenceBinder {
+    /// Whether the binding pass is active.
+    binding: bool,
+
+    /// Current trigger owners, [`None`] for list icons.
+    owners: Vec<Option<Id>>,
+
+    /// Current element names.
+    names: Vec<String>,
+
+    /// Elements by key or name path.
+    lookup: HashMap<String, Id>,
+
+    /// Trigger dependencies of elements.
+    deps: HashMap<Id, Dependency>,
+
+    /// Bound target elements.
+    targets: HashSet<Id>,
+
+    /// Targets of cyclic references.
+    cyclic: Vec<String>,
+}
+
+impl ReferenceBinder {
+    /// Binds all references in the pack.
+    ///
+    /// Returns the targets of cyclic references, which are left unbound.
+    pub fn bind(pack: &mut Pack) -> Vec<String> {
+        let mut binder = Self::default();
+        binder.visit_pack(pack);
+        binder.binding = true;
+        binder.visit_pack(pack);
+        pack.references.set_targets(binder.targets);
+        binder.cyclic
+    }
+
+    /// Registers the element under the key.
+    fn register(&mut self, key: String, id: Id) {
+        if !key.is_empty() {
+            self.lookup.entry(key).or_insert(id); // first wins
+        }
+    }
+
+    /// Resolves the reference target for the owner element.
+    fn resolve(&mut self, owner: Option<Id>, target: &str) -> Option<Id> {
+        let id = *self.lookup.get(target)?;
+        if owner.is_some_and(|owner| self.is_cyclic(owner)) {
+            self.cyclic.push(target.into());
+            None
+        } else {
+            Some(id)
+        }
+    }
+
+    /// Checks whether the trigger of the element depends on itself.
+    fn is_cyclic(&self, start: Id) -> bool {
+        let mut visited = HashSet::new();
+        let mut current = start;
+        loop {
+            current = match self.deps.get(&current) {
+                Some(Dependency::Parent(parent)) => *parent,
+                Some(Dependency::Reference(target)) => match self.lookup.get(target) {
+                    Some(id) => *id,
+                    None => return false,
+                },
+                None => return false,
+            };
+            if current == start {
+                return true;
+            }
+            if !visited.insert(current) {
+                return false; // cycle not involving the start
+            }
+        }
+    }
+}
+
+impl VisitMut for ReferenceBinder {
+    fn visit_pack(&mut self, pack: &mut Pack) {
+        let id = pack.common.id;
+        if !self.binding {
+            self.register(pack.common.key.clone(), id);
+        }
+        self.owners.push(Some(id));
+        self.visit_children_of(pack);
+        self.owners.pop();
+    }
+
+    fn visit_element(&mut self, element: &mut Element) {
+        let id = element.common.id;
+        self.names.push(element.common.name.clone());
+        if !self.binding {
+            self.register(element.common.key.clone(), id);
+            self.register(self.names.join("/"), id);
+        }
+        self.owners.push(Some(id));
+        self.visit_children_of(element);
+        self.owners.pop();
+        self.names.pop();
+    }
+
+    fn visit_list_icon(&mut self, list_icon: &mut ListIcon) {
+        self.owners.push(None);
+        self.visit_children_of(list_icon);
+        self.owners.pop();
+    }
+
+    fn visit_progress_trigger(&mut self, trigger: &mut ProgressTrigger) {
+        let owner = self.owners.last().copied().flatten();
+        if self.binding {
+            let target = trigger
+                .source
+                .reference()
+                .and_then(|target| self.resolve(owner, target));
+            if let Some(target) = target {
+                self.targets.insert(target);
+            }
+            trigger.bind_reference(target);
+        } else if let Some(owner) = owner {
+            if let Some(target) = trigger.source.reference() {
+                self.deps
+                    .insert(owner, Dependency::Reference(target.into()));
+            } else if trigger.source.inherits()
+                && let Some(Some(parent)) = self.owners.iter().rev().nth(1)
+            {
+                self.deps.insert(owner, Dependency::Parent(*parent));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elements::{Common, ElementType, Group},
+        trigger::ProgressSource,
+    };
+
+    fn element(name: &str, source: ProgressSource) -> Element {
+        Element {
+            common: Common {
+                name: name.into(),
+                trigger: ProgressTrigger::with(source),
+                ..Common::default()
+            },
+            ..Element::default()
+        }
+    }
+
+    fn reference(target: &str) -> ProgressSource {
+        ProgressSource::Reference {
+            target: target.into(),
+        }
+    }
+
+    #[test]
+    fn bind() {
+        let mut target = element("Target", ProgressSource::Always);
+        target.common.key = "key".into();
+        let target_id = target.common.id;
+
+        let mut group = element("Group", ProgressSource::Always);
+        group.kind = ElementType::Group(Group {
+            members: vec![target],
+        });
+
+        let mut pack = Pack {
+            elements: vec![
+                element("By Path", reference("Group/Target")),
+                element("By Key", reference("key")),
+                element("Missing", reference("Group/Missing")),
+                group,
+            ],
+            ..Pack::default()
+        };
+
+        assert!(ReferenceBinder::bind(&mut pack).is_empty());
+        assert_eq!(pack.elements[0].common.trigger.reference(), Some(target_id));
+        assert_eq!(pack.elements[1].common.trigger.reference(), Some(target_id));
+        assert_eq!(pack.elements[2].common.trigger.reference(), None);
+        assert!(pack.references.is_target(target_id));
+    }
+
+    #[test]
+    fn cycles() {
+        let mut group = element("Group", reference("Group/Child"));
+        group.kind = ElementType::Group(Group {
+            members: vec![element("Child", ProgressSource::Inherit)],
+        });
+
+        let mut pack = Pack {
+            elements: vec![
+                element("A", reference("B")),
+                element("B", reference("A")),
+                element("C", reference("A")),
+                group,
+            ],
+            ..Pack::default()
+        };
+
+        assert_eq!(ReferenceBinder::bind(&mut pack), ["B", "A", "Group/Child"]);
+        assert_eq!(pack.elements[0].common.trigger.reference(), None);
+        assert_eq!(pack.elements[1].common.trigger.reference(), None);
+        assert_eq!(
+            pack.elements[2].common.trigger.reference(),
+            Some(pack.elements[0].common.id)
+        );
+        assert_eq!(pack.elements[3].common.trigger.reference(), None);
+    }
+}
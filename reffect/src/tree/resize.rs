@@ -1,6 +1,6 @@
 use super::VisitMut;
 use crate::{
-    elements::{Bar, Common, Element, ElementAnchor, IconElement, IconList, Pack, Text},
+    elements::{Bar, Common, Element, ElementAnchor, IconElement, IconList, Pack, Repeater, Text},
     math::ComponentWise,
     render::{helper, input_percent},
 };
@@ -81,6 +81,12 @@ impl VisitMut for Resizer {
         self.visit_children_of(list);
     }
 
+    fn visit_repeater(&mut self, repeater: &mut Repeater) {
+        repeater.size = self.scale_vec(repeater.size);
+        repeater.pad = self.scale(repeater.pad);
+        self.visit_children_of(repeater);
+    }
+
     fn visit_icon_element(&mut self, icon: &mut IconElement) {
         icon.size = self.scale_vec(icon.size);
         self.visit_children_of(icon);
@@ -1,10 +1,52 @@
-use super::VisitMut;
+use super::{ReferenceTargets, VisitMut};
 use crate::{
+    audio::SoundPlay,
     context::{Context, Update},
-    elements::{Bar, Element, Icon, Pack, Text, list::ListIcon},
+    elements::{
+        Bar, Common, Element, ElementState, ElementType, Icon, Pack, PartialProps, Props, Repeater,
+        Text, list::ListIcon,
+    },
+    id::Id,
     profiling::measure,
     trigger::{FilterTrigger, ProgressActive, ProgressTrigger},
 };
+use partial::IntoPartial;
+use std::{
+    cell::{Cell, RefCell},
+    mem,
+};
+
+/// Output of an update.
+#[derive(Debug, Default)]
+pub struct UpdateOutput {
+    /// Whether any trigger has pending timings and needs updates in the next frames.
+    pub pending: Cell<bool>,
+
+    /// Names of timers to start.
+    pub timers: RefCell<Vec<String>>,
+
+    /// Element state changes to publish.
+    pub states: RefCell<Vec<StateChange>>,
+
+    /// Sounds to play.
+    pub sounds: RefCell<Vec<SoundPlay>>,
+}
+
+/// Element state change to publish.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateChange {
+    /// Pack name.
+    pub pack: String,
+
+    /// Element name.
+    pub element: String,
+
+    /// Trigger source name.
+    pub source: String,
+
+    /// New element state.
+    pub state: ElementState,
+}
 
 #[derive(Debug, Clone)]
 pub struct Updater<'ctx, 'p> {
@@ -14,35 +56,51 @@ pub struct Updater<'ctx, 'p> {
     /// Relevant trigger, parent or own.
     trigger: Option<&'p ProgressTrigger>,
 
+    /// Current pack name.
+    pack: &'p str,
+
+    /// Reference targets of the current pack.
+    references: Option<&'p RefCell<ReferenceTargets>>,
+
     /// Whether to force update children.
     force: bool,
+
+    /// Update output.
+    output: &'p UpdateOutput,
 }
 
 impl<'ctx, 'p> Updater<'ctx, 'p> {
-    pub fn force_update(ctx: &'ctx mut Context, packs: &mut [Pack]) {
+    /// Force updates all packs.
+    pub fn force_update(ctx: &'ctx mut Context, packs: &mut [Pack]) -> UpdateOutput {
         ctx.force_update();
-        Self::update(ctx, packs);
+        Self::update(ctx, packs)
     }
 
-    pub fn update(ctx: &'ctx Context, packs: &mut [Pack]) {
+    /// Updates all packs.
+    pub fn update(ctx: &'ctx Context, packs: &mut [Pack]) -> UpdateOutput {
         if ctx.has_update(Update::Map) {
             log::debug!("Map changed to {}", ctx.map.id);
         }
+        let output = UpdateOutput::default();
         measure(
-            || Self::root(ctx).visit_packs(packs),
+            || Self::root(ctx, &output).visit_packs(packs),
             |elapsed| {
                 if ctx.has_any_update() {
                     log::trace!("Update took {elapsed:?} for {}", ctx.updates);
                 }
             },
         );
+        output
     }
 
-    fn root(ctx: &'ctx Context) -> Self {
+    fn root(ctx: &'ctx Context, output: &'p UpdateOutput) -> Self {
         Self {
             ctx,
             trigger: None,
+            pack: "",
+            references: None,
             force: false,
+            output,
         }
     }
 
@@ -55,18 +113,38 @@ impl<'ctx, 'p> Updater<'ctx, 'p> {
         let Self {
             ctx,
             trigger: parent,
+            pack,
+            references,
             force,
+            output,
         } = *self;
 
         let child_updates = filter.update(ctx, force);
         if child_updates.allow || ctx.edit.is_editing() {
-            let force = force || child_updates.force;
-            trigger.update(ctx, parent, force);
+            // pending timings may resolve without context updates, inheriting children follow
+            let force = force || child_updates.force || trigger.is_pending();
+            if let Some(target) = trigger.reference() {
+                // references update with every update, targets may change anytime
+                let references = references.map(RefCell::borrow);
+                let active = references.as_ref().and_then(|refs| refs.get(target));
+                trigger.force_update_with(ctx, active);
+            } else {
+                trigger.update(ctx, parent, force);
+            }
+            if trigger.is_pending() {
+                output.pending.set(true);
+            }
+            if let Some(timer) = trigger.timer_to_start(ctx.now) {
+                output.timers.borrow_mut().push(timer.into());
+            }
 
             Some(Self {
                 ctx: self.ctx,
                 trigger: Some(trigger),
+                pack,
+                references,
                 force,
+                output,
             })
         } else {
             None
@@ -76,23 +154,151 @@ impl<'ctx, 'p> Updater<'ctx, 'p> {
     fn active(&self) -> Option<&'p ProgressActive> {
         self.trigger?.active()
     }
+
+    /// Records the trigger state of the element if it is a reference target.
+    fn record_reference(&self, common: &Common, allowed: bool) {
+        if let Some(references) = self.references {
+            let active = if allowed {
+                common.trigger.active()
+            } else {
+                None
+            };
+            references.borrow_mut().record(common.id, active);
+        }
+    }
+
+    /// Observes the element or pack state for publishing and sound cues.
+    fn observe_state(&self, common: &mut Common, is_pack: bool, allowed: bool, conditions: u32) {
+        if self.ctx.edit.is_editing() || (!common.publish && common.sound.is_none()) {
+            return;
+        }
+
+        let state = ElementState {
+            visible: common.enabled
+                && allowed
+                && common.filter.is_active(self.ctx)
+                && self.ctx.toggles.is_enabled(&common.toggle)
+                && common.trigger.is_visible(),
+            conditions,
+        };
+
+        if let Some(sound) = &mut common.sound
+            && let Some(play) = sound.update(state, self.ctx.now)
+        {
+            self.output.sounds.borrow_mut().push(play);
+        }
+
+        if common.publish {
+            if let Some(state) = common.publish_state.update(state, self.ctx.now) {
+                self.output.states.borrow_mut().push(StateChange {
+                    pack: if is_pack {
+                        common.name.clone()
+                    } else {
+                        self.pack.into()
+                    },
+                    element: common.name.clone(),
+                    source: common.trigger.source.as_ref().into(),
+                    state,
+                });
+            }
+            if common.publish_state.is_pending() {
+                self.output.pending.set(true);
+            }
+        }
+    }
+
+    fn update_props<T>(&self, props: &mut Props<T>)
+    where
+        T: Clone + IntoPartial,
+        T::Partial: PartialProps<T>,
+    {
+        props.update(self.ctx, self.active(), self.force);
+        if props.is_pending(self.ctx) {
+            self.output.pending.set(true);
+        }
+    }
+
+    /// Updates a repeater with the given element id.
+    fn update_repeater(&mut self, repeater: &mut Repeater, id: Id) {
+        let edit = &self.ctx.edit;
+        if edit.is_edited_or_parent(id) {
+            if edit.is_edited_parent(id) {
+                // template is edited, instances are recreated afterwards
+                repeater.clear_instances();
+            }
+            self.visit_children_of(repeater);
+        } else {
+            let count = self
+                .ctx
+                .group
+                .as_ref()
+                .map_or(0, |group| group.members.len());
+            let prev = repeater.sync_instances(count);
+            for (i, instance) in repeater.instances_mut().iter_mut().enumerate() {
+                let mut child = Self {
+                    force: self.force || i >= prev,
+                    ..self.clone()
+                };
+                child.visit_elements(instance);
+            }
+        }
+    }
 }
 
 impl VisitMut for Updater<'_, '_> {
     fn visit_pack(&mut self, pack: &mut Pack) {
         let Pack {
-            common, elements, ..
+            common,
+            elements,
+            references,
+            ..
         } = pack;
-        if let Some(mut child) = self.update_and_push(&mut common.filter, &mut common.trigger) {
+        let cell = RefCell::new(mem::take(references));
+        let updater = Updater {
+            references: Some(&cell),
+            ..self.clone()
+        };
+        let allowed = if let Some(mut child) =
+            updater.update_and_push(&mut common.filter, &mut common.trigger)
+        {
+            child.pack = &common.name;
             child.visit_elements(elements);
+            true
+        } else {
+            let hidden = Updater {
+                pack: &common.name,
+                ..updater.clone()
+            };
+            HiddenObserver(&hidden).visit_elements(elements);
+            false
+        };
+        updater.record_reference(common, allowed);
+        updater.observe_state(common, true, allowed, 0);
+
+        *references = cell.into_inner();
+        if references.finish() {
+            // forward references pick up the changes next update
+            self.output.pending.set(true);
         }
     }
 
     fn visit_element(&mut self, element: &mut Element) {
         let Element { common, kind } = element;
-        if let Some(mut child) = self.update_and_push(&mut common.filter, &mut common.trigger) {
-            child.visit_element_type(kind);
-        }
+        let allowed = if let Some(mut child) =
+            self.update_and_push(&mut common.filter, &mut common.trigger)
+        {
+            if let ElementType::Repeater(repeater) = kind {
+                child.update_repeater(repeater, common.id);
+            } else {
+                child.visit_element_type(kind);
+            }
+            true
+        } else {
+            HiddenObserver(self).visit_element_type(kind);
+            false
+        };
+        self.record_reference(common, allowed);
+        self.observe_state(common, false, allowed, kind.active_conditions());
     }
 
     fn visit_list_icon(&mut self, list_icon: &mut ListIcon) {
@@ -103,18 +309,36 @@ impl VisitMut for Updater<'_, '_> {
     }
 
     fn visit_icon(&mut self, icon: &mut Icon) {
-        icon.props.update(self.ctx, self.active(), self.force);
+        self.update_props(&mut icon.props);
     }
 
     fn visit_text(&mut self, text: &mut Text) {
         if self.force {
             text.reprocess_next_frame();
         }
-        text.props.update(self.ctx, self.active(), self.force);
+        self.update_props(&mut text.props);
     }
 
     fn visit_bar(&mut self, bar: &mut Bar) {
-        bar.props.update(self.ctx, self.active(), self.force);
+        self.update_props(&mut bar.props);
+    }
+}
+
+/// Observes descendants of a disallowed pack or element as hidden.
+struct HiddenObserver<'u, 'ctx, 'p>(&'u Updater<'ctx, 'p>);
+
+impl VisitMut for HiddenObserver<'_, '_, '_> {
+    fn visit_element(&mut self, element: &mut Element) {
+        let Element { common, kind } = element;
+        self.0.record_reference(common, false);
+        self.0.observe_state(common, false, false, 0);
+        self.visit_element_type(kind);
+    }
+
+    fn visit_repeater(&mut self, repeater: &mut Repeater) {
+        for instance in repeater.instances_mut() {
+            self.visit_elements(instance);
+        }
     }
 }
 
@@ -122,8 +346,10 @@ impl VisitMut for Updater<'_, '_> {
 mod tests {
     use super::*;
     use crate::{
-        context::{Gear, PlayerInfo, Weapon},
-        trigger::{ChildUpdates, ProgressSource, ProgressTrigger},
+        context::{Buff, Gear, PlayerBuffInfo, PlayerInfo, Updates, Weapon},
+        elements::{ElementType, Group},
+        tree::ReferenceBinder,
+        trigger::{ChildUpdates, ProgressSource, ProgressTiming, ProgressTrigger},
     };
 
     fn create_ctx() -> Context {
@@ -185,7 +411,8 @@ mod tests {
         let trigger_ptr = &raw const trigger;
 
         ctx.force_update();
-        let parent = Updater::root(&ctx)
+        let output = UpdateOutput::default();
+        let parent = Updater::root(&ctx, &output)
             .update_and_push(&mut filter, &mut trigger)
             .expect("no parent visit");
 
@@ -204,4 +431,190 @@ mod tests {
         assert_eq!(&raw const *active_trigger, trigger_ptr);
         assert_eq!(child.force, true);
     }
+
+    #[test]
+    fn timing() {
+        let mut ctx = create_ctx();
+        let mut filter = FilterTrigger::default();
+        let mut trigger = ProgressTrigger::buff();
+        trigger.source = ProgressSource::Buff {
+            combatant: Default::default(),
+            ids: vec![740],
+        };
+        trigger.timing = ProgressTiming {
+            delay: 500,
+            linger: 1000,
+            ..ProgressTiming::DEFAULT
+        };
+
+        let mut update = |ctx: &Context| {
+            let output = UpdateOutput::default();
+            let _ = Updater::root(ctx, &output).update_and_push(&mut filter, &mut trigger);
+            (trigger.is_visible(), output.pending.get())
+        };
+
+        ctx.force_update();
+        assert_eq!(update(&ctx), (false, false));
+
+        ctx.now = 1000;
+        ctx.player.buff_info = Ok(PlayerBuffInfo {
+            buffs: [(740, Buff::hidden_duration())].into(),
+            ..Default::default()
+        });
+        ctx.updates = Update::PlayerBuffs.into();
+        assert_eq!(update(&ctx), (false, true));
+
+        // pending delay updates without context updates
+        ctx.now = 1200;
+        ctx.updates = Updates::EMPTY;
+        assert_eq!(update(&ctx), (false, true));
+        ctx.now = 1500;
+        assert_eq!(update(&ctx), (true, false));
+
+        ctx.now = 2000;
+        ctx.player.buff_info = Ok(PlayerBuffInfo::default());
+        ctx.updates = Update::PlayerBuffs.into();
+        assert_eq!(update(&ctx), (true, true));
+
+        ctx.now = 2500;
+        ctx.updates = Updates::EMPTY;
+        assert_eq!(update(&ctx), (true, true));
+        ctx.now = 3000;
+        assert_eq!(update(&ctx), (false, false));
+    }
+
+    #[test]
+    fn forward_reference() {
+        let mut ctx = create_ctx();
+        let element = |name: &str, source| Element {
+            common: Common {
+                name: name.into(),
+                trigger: ProgressTrigger::with(source),
+                ..Common::default()
+            },
+            ..Element::default()
+        };
+        let mut pack = Pack {
+            common: Common::default(),
+            elements: vec![
+                element(
+                    "Reference",
+                    ProgressSource::Reference {
+                        target: "Target".into(),
+                    },
+                ),
+                element("Target", ProgressSource::Always),
+            ],
+            ..Pack::default()
+        };
+        ReferenceBinder::bind(&mut pack);
+        let packs = std::slice::from_mut(&mut pack);
+
+        // target is updated after the reference
+        let output = Updater::force_update(&mut ctx, packs);
+        assert!(output.pending.get());
+        assert!(!packs[0].elements[0].common.trigger.is_visible());
+
+        ctx.updates = Updates::EMPTY;
+        let output = Updater::update(&ctx, packs);
+        assert!(!output.pending.get());
+        assert!(packs[0].elements[0].common.trigger.is_visible());
+    }
+
+    #[test]
+    fn inherit_pending() {
+        let mut ctx = create_ctx();
+        let mut parent = ProgressTrigger::buff();
+        parent.source = ProgressSource::Buff {
+            combatant: Default::default(),
+            ids: vec![740],
+        };
+        parent.timing = ProgressTiming {
+            delay: 500,
+            ..ProgressTiming::DEFAULT
+        };
+        let mut pack = Pack {
+            elements: vec![Element {
+                common: Common {
+                    trigger: parent,
+                    ..Common::default()
+                },
+                kind: ElementType::Group(Group {
+                    members: vec![Element {
+                        common: Common {
+                            trigger: ProgressTrigger::with(ProgressSource::Inherit),
+                            ..Common::default()
+                        },
+                        ..Element::default()
+                    }],
+                }),
+            }],
+            ..Pack::default()
+        };
+        let packs = std::slice::from_mut(&mut pack);
+        let child_visible = |packs: &[Pack]| match &packs[0].elements[0].kind {
+            ElementType::Group(group) => group.members[0].common.trigger.is_visible(),
+            _ => unreachable!(),
+        };
+
+        Updater::force_update(&mut ctx, packs);
+        assert!(!child_visible(packs));
+
+        ctx.now = 1000;
+        ctx.player.buff_info = Ok(PlayerBuffInfo {
+            buffs: [(740, Buff::hidden_duration())].into(),
+            ..Default::default()
+        });
+        ctx.updates = Update::PlayerBuffs.into();
+        let output = Updater::update(&ctx, packs);
+        assert!(output.pending.get());
+        assert!(!child_visible(packs));
+
+        // delay passes without context updates
+        ctx.now = 1500;
+        ctx.updates = Updates::EMPTY;
+        Updater::update(&ctx, packs);
+        assert!(packs[0].elements[0].common.trigger.is_visible());
+        assert!(child_visible(packs));
+    }
+
+    #[test]
+    fn hidden_children() {
+        let mut ctx = create_ctx();
+        ctx.player.gear = Ok(Gear {
+            weapons: Weapon::AxeMain.into(),
+            ..Gear::empty()
+        });
+        let mut pack = Pack {
+            common: Common {
+                filter: create_filter(),
+                ..Common::default()
+            },
+            elements: vec![Element {
+                common: Common {
+                    name: "Child".into(),
+                    trigger: ProgressTrigger::with(ProgressSource::Always),
+                    publish: true,
+                    ..Common::default()
+                },
+                ..Element::default()
+            }],
+            ..Pack::default()
+        };
+        let packs = std::slice::from_mut(&mut pack);
+
+        let output = Updater::force_update(&mut ctx, packs);
+        let states = output.states.into_inner();
+        assert_eq!(states.len(), 1);
+        assert!(states[0].state.visible);
+
+        // disallowed pack hides its children
+        ctx.now = 1000;
+        ctx.player.gear = Ok(Gear::empty());
+        ctx.updates = Update::PlayerGear.into();
+        let output = Updater::update(&ctx, packs);
+        let states = output.states.into_inner();
+        assert_eq!(states.len(), 1);
+        assert!(!states[0].state.visible);
+    }
 }
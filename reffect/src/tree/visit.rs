@@ -1,7 +1,7 @@
 use crate::{
     elements::{
-        Bar, Common, Element, ElementType, Group, Icon, IconElement, IconList, Pack, Text,
-        list::ListIcon,
+        Bar, Common, Element, ElementType, Group, Icon, IconElement, IconList, Pack, Repeater,
+        Text, list::ListIcon,
     },
     trigger::{FilterTrigger, ProgressTrigger},
 };
@@ -39,6 +39,10 @@ pub trait VisitMut: Sized {
         self.visit_children_of(list);
     }
 
+    fn visit_repeater(&mut self, repeater: &mut Repeater) {
+        self.visit_children_of(repeater);
+    }
+
     fn visit_list_icon(&mut self, list_icon: &mut ListIcon) {
         self.visit_children_of(list_icon);
     }
@@ -100,6 +104,7 @@ impl Walk for ElementType {
             Self::IconList(list) => visitor.visit_icon_list(list),
             Self::Text(text) => visitor.visit_text(text),
             Self::Bar(bar) => visitor.visit_bar(bar),
+            Self::Repeater(repeater) => visitor.visit_repeater(repeater),
         }
     }
 }
@@ -110,6 +115,12 @@ impl Walk for Group {
     }
 }
 
+impl Walk for Repeater {
+    fn walk_mut(&mut self, visitor: &mut impl VisitMut) {
+        visitor.visit_elements(&mut self.members);
+    }
+}
+
 impl Walk for IconList {
     fn walk_mut(&mut self, visitor: &mut impl VisitMut) {
         for icon in &mut self.icons {
@@ -0,0 +1,112 @@
+use super::{AmountType, Edge, ProgressActive, ProgressThreshold, ThresholdType};
+use crate::{
+    context::Context,
+    render::{enum_combo_bitflags, helper, input_seconds},
+    serde::bitflags,
+};
+use const_default::ConstDefault;
+use enumflags2::BitFlags;
+use nexus::imgui::{ComboBoxFlags, Ui};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Recently changed trigger.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct ChangeTrigger {
+    /// Threshold to watch for changes.
+    pub threshold: ProgressThreshold,
+
+    /// Edges to react to, any if empty.
+    #[serde(with = "bitflags")]
+    #[cfg_attr(feature = "schema", schemars(with = "bitflags::Schema<Edge>"))]
+    pub edges: BitFlags<Edge>,
+
+    /// Time the trigger stays active after a change in milliseconds.
+    pub duration: u32,
+
+    /// Memoized previous threshold state.
+    #[serde(skip)]
+    met: Option<bool>,
+
+    /// Memoized timestamp of last change.
+    #[serde(skip)]
+    changed: Option<u32>,
+}
+
+impl ConstDefault for ChangeTrigger {
+    const DEFAULT: Self = Self {
+        threshold: ProgressThreshold {
+            threshold_type: ThresholdType::Present,
+            amount_type: AmountType::DEFAULT,
+        },
+        edges: BitFlags::EMPTY,
+        duration: 3000,
+        met: None,
+        changed: None,
+    };
+}
+
+impl Default for ChangeTrigger {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl ChangeTrigger {
+    /// Compares the threshold for the active progress against the previous state.
+    pub fn observe(&mut self, ctx: &Context, active: &ProgressActive) {
+        let met = self.threshold.is_met(active, ctx);
+        if self.met.is_some_and(|prev| prev != met) {
+            let edge = if met { Edge::Gain } else { Edge::Loss };
+            if self.edges.is_empty() || self.edges.contains(edge) {
+                self.changed = Some(ctx.now);
+            }
+        }
+        self.met = Some(met);
+    }
+
+    /// Returns whether the threshold changed recently.
+    pub fn is_active(&self, ctx: &Context) -> bool {
+        self.changed
+            .is_some_and(|changed| ctx.now.saturating_sub(changed) < self.duration)
+    }
+
+    /// Renders recently changed trigger options.
+    pub fn render_options(&mut self, ui: &Ui) -> bool {
+        let mut changed = false;
+
+        changed |= self.threshold.render_options(ui);
+
+        changed |= enum_combo_bitflags(ui, "Edges", &mut self.edges, ComboBoxFlags::empty());
+        helper(ui, || {
+            ui.text("Gain: threshold became met");
+            ui.text("Loss: threshold stopped being met");
+            ui.text("Reacts to any change if empty");
+        });
+
+        changed |= input_seconds(ui, "Duration", &mut self.duration);
+        helper(ui, || {
+            ui.text("Time the condition stays active after a change")
+        });
+
+        if changed {
+            self.met = None;
+            self.changed = None;
+        }
+
+        changed
+    }
+}
+
+impl fmt::Display for ChangeTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let verb = match self.edges.exactly_one() {
+            Some(Edge::Gain) => "Became",
+            Some(Edge::Loss) => "Stopped",
+            None => "Changed",
+        };
+        write!(f, "{verb} {}", self.threshold)
+    }
+}
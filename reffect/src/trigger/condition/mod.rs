@@ -1,12 +1,14 @@
+mod mode;
 mod trigger;
 
-pub use self::trigger::*;
+pub use self::{mode::*, trigger::*};
 
 use super::{ProgressActive, ProgressSource};
 use crate::{context::Context, elements::PartialProps};
 use nexus::imgui::Ui;
 use partial::{IntoPartial, PartialOps};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 // TODO: add common props: pos, opacity, animation
 
@@ -22,6 +24,11 @@ pub struct Condition<T>
 where
     T: IntoPartial,
 {
+    /// Optional name shown in the editor.
+    pub name: String,
+
+    pub enabled: bool,
+
     pub trigger: ConditionTrigger,
     pub properties: T::Partial,
 }
@@ -30,14 +37,29 @@ impl<T> Condition<T>
 where
     T: IntoPartial,
 {
+    /// Observes the progress without applying the condition.
+    pub fn observe(&mut self, ctx: &Context, active: &ProgressActive) {
+        if self.enabled {
+            self.trigger.observe(ctx, active);
+        }
+    }
+
     /// Processes the condition.
-    pub fn process(&mut self, value: &mut T, ctx: &Context, active: &ProgressActive)
+    ///
+    /// Returns whether the condition is active.
+    pub fn process(&mut self, value: &mut T, ctx: &Context, active: &ProgressActive) -> bool
     where
         T::Partial: Clone,
     {
-        if self.trigger.is_active(ctx, active) {
+        if !self.enabled {
+            return false;
+        }
+        self.trigger.observe(ctx, active);
+        let is_active = self.trigger.is_active(ctx, active);
+        if is_active {
             value.set(self.properties.clone());
         }
+        is_active
     }
 
     /// Renders condition options.
@@ -45,6 +67,9 @@ where
     where
         T::Partial: PartialProps<T>,
     {
+        ui.input_text("Name", &mut self.name).build();
+        ui.spacing();
+
         self.trigger.render_options(ui, ctx, source);
         ui.spacing();
         self.properties.render_options(ui, base);
@@ -58,8 +83,23 @@ where
 {
     fn default() -> Self {
         Self {
+            name: String::new(),
+            enabled: true,
             trigger: ConditionTrigger::default(),
             properties: T::Partial::empty(),
         }
     }
 }
+
+impl<T> fmt::Display for Condition<T>
+where
+    T: IntoPartial,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.name.is_empty() {
+            write!(f, "{}", self.trigger)
+        } else {
+            write!(f, "{}", self.name)
+        }
+    }
+}
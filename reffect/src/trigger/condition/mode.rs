@@ -0,0 +1,45 @@
+use const_default::ConstDefault;
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumIter, IntoStaticStr, VariantArray};
+
+/// Condition evaluation mode.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Display,
+    AsRefStr,
+    IntoStaticStr,
+    EnumIter,
+    VariantArray,
+    Serialize,
+    Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ConditionMode {
+    /// Apply all matching conditions in order.
+    #[default]
+    #[strum(serialize = "All matching")]
+    All,
+
+    /// Apply only the first matching condition.
+    #[strum(serialize = "First match")]
+    First,
+}
+
+impl ConditionMode {
+    /// Checks whether evaluation should stop after a match.
+    pub fn stops_at_match(&self) -> bool {
+        matches!(self, Self::First)
+    }
+}
+
+impl ConstDefault for ConditionMode {
+    const DEFAULT: Self = Self::All;
+}
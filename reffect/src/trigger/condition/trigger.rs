@@ -1,9 +1,12 @@
 use super::ProgressActive;
 use crate::{
-    context::{Context, Updateable},
+    context::{Context, Update, Updateable},
     enums::check_variant_array,
     render::{Validation, enum_combo},
-    trigger::{AbilityInfoTrigger, MapTrigger, PlayerTrigger, ProgressSource, ProgressThreshold},
+    trigger::{
+        AbilityInfoTrigger, ChangeTrigger, DayCycleTrigger, GroupTrigger, MapTrigger,
+        PlayerTrigger, ProgressSource, ProgressThreshold, UiTrigger, ZoneTrigger,
+    },
 };
 use const_default::ConstDefault;
 use nexus::imgui::{ComboBoxFlags, Ui};
@@ -27,17 +30,35 @@ pub enum ConditionTrigger {
     #[strum(serialize = "Ability Info")]
     AbilityInfo(AbilityInfoTrigger),
 
+    #[strum(serialize = "Recently Changed")]
+    RecentlyChanged(ChangeTrigger),
+
     Player(PlayerTrigger),
 
     Map(MapTrigger),
+
+    Group(GroupTrigger),
+
+    Zone(ZoneTrigger),
+
+    #[strum(serialize = "Day Cycle")]
+    DayCycle(DayCycleTrigger),
+
+    #[strum(serialize = "Game UI")]
+    Ui(UiTrigger),
 }
 
 impl VariantArray for ConditionTrigger {
     const VARIANTS: &'static [Self] = &[
         Self::ProgressThreshold(ProgressThreshold::DEFAULT),
         Self::AbilityInfo(AbilityInfoTrigger::DEFAULT),
+        Self::RecentlyChanged(ChangeTrigger::DEFAULT),
         Self::Player(PlayerTrigger::DEFAULT),
         Self::Map(MapTrigger::DEFAULT),
+        Self::Group(GroupTrigger::DEFAULT),
+        Self::Zone(ZoneTrigger::DEFAULT),
+        Self::DayCycle(DayCycleTrigger::DEFAULT),
+        Self::Ui(UiTrigger::DEFAULT),
     ];
 }
 
@@ -59,8 +80,28 @@ impl ConditionTrigger {
         match self {
             Self::ProgressThreshold(threshold) => threshold.is_met(active, ctx),
             Self::AbilityInfo(ability_state) => ability_state.is_present(active),
+            Self::RecentlyChanged(change) => change.is_active(ctx),
             Self::Player(player) => player.is_active(ctx),
             Self::Map(map) => map.is_active(),
+            Self::Group(group) => group.is_active(),
+            Self::Zone(zone) => zone.is_active(ctx),
+            Self::DayCycle(day_cycle) => day_cycle.is_active(),
+            Self::Ui(ui) => ui.is_active(ctx),
+        }
+    }
+
+    /// Observes the active progress for conditions tracking changes.
+    pub fn observe(&mut self, ctx: &Context, active: &ProgressActive) {
+        if let Self::RecentlyChanged(change) = self {
+            change.observe(ctx, active);
+        }
+    }
+
+    /// Checks whether the condition needs updates to expire.
+    pub fn is_pending(&self, ctx: &Context) -> bool {
+        match self {
+            Self::RecentlyChanged(change) => change.is_active(ctx),
+            _ => false,
         }
     }
 
@@ -76,11 +117,13 @@ impl ConditionTrigger {
                 ProgressSource::Ability { .. } | ProgressSource::SkillbarSlot { .. } => {
                     Validation::Ok
                 }
-                ProgressSource::Inherit => {
-                    Validation::Warn("Inherited trigger source must be ability-like")
+                ProgressSource::Inherit | ProgressSource::Reference { .. } => {
+                    Validation::Warn("Inherited or referenced trigger source must be ability-like")
                 }
                 ProgressSource::Always
                 | ProgressSource::Buff { .. }
+                | ProgressSource::BuffCategory { .. }
+                | ProgressSource::Consumable { .. }
                 | ProgressSource::Health { .. }
                 | ProgressSource::HealthReduction
                 | ProgressSource::Barrier { .. }
@@ -88,11 +131,22 @@ impl ConditionTrigger {
                 | ProgressSource::Endurance
                 | ProgressSource::PrimaryResource
                 | ProgressSource::SecondaryResource
-                | ProgressSource::ResourceRate => {
+                | ProgressSource::ResourceRate
+                | ProgressSource::DayCycle { .. }
+                | ProgressSource::Event { .. }
+                | ProgressSource::Timer { .. }
+                | ProgressSource::Variable { .. } => {
                     Validation::Error("Condition requires an ability-like trigger source")
                 }
             },
-            Self::ProgressThreshold(_) | Self::Player(_) | Self::Map(_) => Validation::Ok,
+            Self::ProgressThreshold(_)
+            | Self::RecentlyChanged(_)
+            | Self::Player(_)
+            | Self::Map(_)
+            | Self::Group(_)
+            | Self::Zone(_)
+            | Self::DayCycle(_)
+            | Self::Ui(_) => Validation::Ok,
         }
     }
 
@@ -110,12 +164,27 @@ impl ConditionTrigger {
             Self::AbilityInfo(ability_state) => {
                 ability_state.render_options(ui);
             }
+            Self::RecentlyChanged(change) => {
+                change.render_options(ui);
+            }
             Self::Player(player) => {
                 player.render_options(ui, ctx);
             }
             Self::Map(map) => {
                 map.render_options(ui, ctx);
             }
+            Self::Group(group) => {
+                group.render_options(ui, ctx);
+            }
+            Self::Zone(zone) => {
+                zone.render_options(ui, ctx);
+            }
+            Self::DayCycle(day_cycle) => {
+                day_cycle.render_options(ui, ctx);
+            }
+            Self::Ui(trigger) => {
+                trigger.render_options(ui);
+            }
         }
     }
 }
@@ -123,25 +192,41 @@ impl ConditionTrigger {
 impl Updateable for ConditionTrigger {
     fn needs_update(&self, ctx: &Context) -> bool {
         match self {
-            Self::ProgressThreshold(_) | Self::AbilityInfo(_) => false,
+            Self::ProgressThreshold(_) | Self::AbilityInfo(_) | Self::RecentlyChanged(_) => false,
+            Self::Zone(_) => ctx.has_update_or_edit(Update::Map | Update::Position),
+            Self::Ui(_) => ctx.has_update_or_edit(Update::Ui),
             Self::Player(player) => player.needs_update(ctx),
             Self::Map(map) => map.needs_update(ctx),
+            Self::Group(group) => group.needs_update(ctx),
+            Self::DayCycle(day_cycle) => day_cycle.needs_update(ctx),
         }
     }
 
     fn force_update(&mut self, ctx: &Context) {
         match self {
-            Self::ProgressThreshold(_) | Self::AbilityInfo(_) => {}
+            Self::ProgressThreshold(_)
+            | Self::AbilityInfo(_)
+            | Self::RecentlyChanged(_)
+            | Self::Zone(_)
+            | Self::Ui(_) => {}
             Self::Player(player) => player.force_update(ctx),
             Self::Map(map) => map.force_update(ctx),
+            Self::Group(group) => group.force_update(ctx),
+            Self::DayCycle(day_cycle) => day_cycle.force_update(ctx),
         }
     }
 
     fn update_if_need(&mut self, ctx: &Context) {
         match self {
-            Self::ProgressThreshold(_) | Self::AbilityInfo(_) => {}
+            Self::ProgressThreshold(_)
+            | Self::AbilityInfo(_)
+            | Self::RecentlyChanged(_)
+            | Self::Zone(_)
+            | Self::Ui(_) => {}
             Self::Player(player) => player.update_if_need(ctx),
             Self::Map(map) => map.update_if_need(ctx),
+            Self::Group(group) => group.update_if_need(ctx),
+            Self::DayCycle(day_cycle) => day_cycle.update_if_need(ctx),
         }
     }
 }
@@ -151,8 +236,13 @@ impl fmt::Display for ConditionTrigger {
         match self {
             Self::ProgressThreshold(threshold) => threshold.fmt(f),
             Self::AbilityInfo(ability_state) => ability_state.fmt(f),
+            Self::RecentlyChanged(change) => change.fmt(f),
             Self::Player(_) => write!(f, "Player"),
             Self::Map(_) => write!(f, "Map"),
+            Self::Group(_) => write!(f, "Group"),
+            Self::Zone(_) => write!(f, "Zone"),
+            Self::DayCycle(_) => write!(f, "Day Cycle"),
+            Self::Ui(_) => write!(f, "Game UI"),
         }
     }
 }
@@ -0,0 +1,73 @@
+use super::TriggerMode;
+use crate::{
+    context::{Context, DayCycle, Update, Updateable},
+    render::{enum_combo_bitflags, helper},
+    serde::bitflags,
+};
+use const_default::ConstDefault;
+use enumflags2::BitFlags;
+use nexus::imgui::{ComboBoxFlags, Ui};
+use serde::{Deserialize, Serialize};
+
+/// Day & night cycle trigger.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct DayCycleTrigger {
+    /// Day cycle phases.
+    #[serde(with = "bitflags")]
+    #[cfg_attr(feature = "schema", schemars(with = "bitflags::Schema<DayCycle>"))]
+    pub phases: BitFlags<DayCycle>,
+
+    #[serde(skip)]
+    active: bool,
+}
+
+impl ConstDefault for DayCycleTrigger {
+    const DEFAULT: Self = Self {
+        phases: BitFlags::EMPTY,
+        active: true,
+    };
+}
+
+impl Default for DayCycleTrigger {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl DayCycleTrigger {
+    /// Returns whether the day cycle trigger is active.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+impl Updateable for DayCycleTrigger {
+    fn needs_update(&self, ctx: &Context) -> bool {
+        ctx.has_update_or_edit(Update::Time)
+    }
+
+    fn force_update(&mut self, ctx: &Context) {
+        let (phase, _) = ctx.time.day_cycle();
+        self.active = TriggerMode::Any.check_flags(self.phases, phase);
+    }
+}
+
+impl DayCycleTrigger {
+    /// Renders day cycle trigger options.
+    pub fn render_options(&mut self, ui: &Ui, ctx: &Context) -> bool {
+        let _id = ui.push_id("daycycle");
+
+        let changed =
+            enum_combo_bitflags(ui, "Day Cycle", &mut self.phases, ComboBoxFlags::empty());
+        helper(ui, || ui.text("Time of day in central Tyria"));
+
+        if changed {
+            // ensure fresh state after changed
+            self.force_update(ctx);
+        }
+
+        changed
+    }
+}
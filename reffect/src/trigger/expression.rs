@@ -0,0 +1,251 @@
+use super::{CombatTrigger, GearTrigger, MapTrigger, PlayerTrigger, TriggerMode};
+use crate::{
+    action::Action,
+    colors,
+    context::{Context, Mount, Updateable},
+    enums::check_variant_array,
+    render::{collapsing_header_same_line_end, enum_combo, enum_combo_bitflags, helper},
+    serde::bitflags,
+};
+use const_default::ConstDefault;
+use enumflags2::BitFlags;
+use nexus::imgui::{ComboBoxFlags, StyleColor, TreeNode, TreeNodeFlags, Ui};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use strum::{AsRefStr, EnumCount, EnumIter, IntoStaticStr, VariantArray};
+
+/// Boolean filter expression.
+#[derive(
+    Debug, Clone, PartialEq, AsRefStr, IntoStaticStr, EnumIter, EnumCount, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FilterExpression {
+    /// Child expressions combined with the trigger mode.
+    ///
+    /// Active if empty.
+    #[strum(serialize = "Group")]
+    Group {
+        mode: TriggerMode,
+        children: Vec<FilterExpression>,
+    },
+
+    Player(PlayerTrigger),
+
+    Map(MapTrigger),
+
+    Combat(CombatTrigger),
+
+    Mount(
+        #[serde(with = "bitflags")]
+        #[cfg_attr(feature = "schema", schemars(with = "bitflags::Schema<Mount>"))]
+        BitFlags<Mount>,
+    ),
+
+    Gear(GearTrigger),
+}
+
+impl VariantArray for FilterExpression {
+    const VARIANTS: &'static [Self] = &[
+        Self::DEFAULT,
+        Self::Player(PlayerTrigger::DEFAULT),
+        Self::Map(MapTrigger::DEFAULT),
+        Self::Combat(CombatTrigger::DEFAULT),
+        Self::Mount(BitFlags::EMPTY),
+        Self::Gear(GearTrigger::DEFAULT),
+    ];
+}
+
+const _: () = check_variant_array::<FilterExpression>();
+
+impl ConstDefault for FilterExpression {
+    const DEFAULT: Self = Self::Group {
+        mode: TriggerMode::All,
+        children: Vec::new(),
+    };
+}
+
+impl Default for FilterExpression {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl FilterExpression {
+    pub fn load(&mut self) {
+        match self {
+            Self::Group { children, .. } => {
+                for child in children {
+                    child.load();
+                }
+            }
+            Self::Player(player) => player.load(),
+            Self::Map(_) | Self::Combat(_) | Self::Mount(_) | Self::Gear(_) => {}
+        }
+    }
+
+    /// Checks whether the expression is active.
+    pub fn is_active(&self, ctx: &Context) -> bool {
+        match self {
+            Self::Group { mode, children } => {
+                mode.check_slice(children, |child| child.is_active(ctx))
+            }
+            Self::Player(player) => player.is_active(ctx),
+            Self::Map(map) => map.is_active(),
+            Self::Combat(combat) => combat.is_active(ctx),
+            Self::Mount(mounts) => {
+                TriggerMode::Any.check_flags_optional(*mounts, ctx.player.mount.ok())
+            }
+            Self::Gear(gear) => gear.is_active(),
+        }
+    }
+
+    /// Renders expression options.
+    pub fn render_options(&mut self, ui: &Ui, ctx: &Context) {
+        if enum_combo(ui, "Type", self, ComboBoxFlags::empty()).is_some() {
+            // ensure fresh state after changed
+            self.force_update(ctx);
+        }
+
+        match self {
+            Self::Group { mode, children } => {
+                mode.render_options(ui, "Mode");
+                helper(ui, || {
+                    ui.text("How to combine the expressions below");
+                    ui.text("Use \"None of\" for a negation");
+                    ui.text("Active if empty");
+                });
+
+                let mut action = Action::new();
+                for (i, child) in children.iter_mut().enumerate() {
+                    let _id = ui.push_id(i as i32);
+
+                    let token = TreeNode::new("expr")
+                        .label::<&str, _>(child.to_string())
+                        .flags(TreeNodeFlags::ALLOW_ITEM_OVERLAP | TreeNodeFlags::SPAN_AVAIL_WIDTH)
+                        .push(ui);
+
+                    {
+                        let size_x = ui.frame_height();
+                        let [spacing_x, _] = ui.clone_style().item_spacing;
+                        collapsing_header_same_line_end(ui, 3.0 * size_x + 2.0 * spacing_x);
+
+                        let _style = ui.push_style_color(StyleColor::Button, colors::TRANSPARENT);
+                        action.render_buttons(ui, i);
+                    }
+
+                    if let Some(_token) = token {
+                        child.render_options(ui, ctx);
+                    }
+                }
+                action.perform(children);
+
+                if ui.button("Add Expression") {
+                    let mut child = Self::Player(PlayerTrigger::DEFAULT);
+                    child.force_update(ctx);
+                    children.push(child);
+                }
+            }
+            Self::Player(player) => player.render_options(ui, ctx),
+            Self::Map(map) => {
+                map.render_options(ui, ctx);
+            }
+            Self::Combat(combat) => combat.render_options(ui),
+            Self::Mount(mounts) => {
+                enum_combo_bitflags(ui, "Mount", mounts, ComboBoxFlags::HEIGHT_LARGE);
+            }
+            Self::Gear(gear) => {
+                gear.render_options(ui, ctx);
+            }
+        }
+    }
+}
+
+impl Updateable for FilterExpression {
+    fn needs_update(&self, ctx: &Context) -> bool {
+        match self {
+            Self::Group { children, .. } => children.iter().any(|child| child.needs_update(ctx)),
+            Self::Player(player) => player.needs_update(ctx),
+            Self::Map(map) => map.needs_update(ctx),
+            Self::Combat(_) | Self::Mount(_) => false,
+            Self::Gear(gear) => gear.needs_update(ctx),
+        }
+    }
+
+    fn force_update(&mut self, ctx: &Context) {
+        match self {
+            Self::Group { children, .. } => {
+                for child in children {
+                    child.force_update(ctx);
+                }
+            }
+            Self::Player(player) => player.force_update(ctx),
+            Self::Map(map) => map.force_update(ctx),
+            Self::Combat(_) | Self::Mount(_) => {}
+            Self::Gear(gear) => gear.force_update(ctx),
+        }
+    }
+
+    fn update_if_need(&mut self, ctx: &Context) {
+        match self {
+            Self::Group { children, .. } => {
+                for child in children {
+                    child.update_if_need(ctx);
+                }
+            }
+            Self::Player(player) => player.update_if_need(ctx),
+            Self::Map(map) => map.update_if_need(ctx),
+            Self::Combat(_) | Self::Mount(_) => {}
+            Self::Gear(gear) => gear.update_if_need(ctx),
+        }
+    }
+}
+
+impl fmt::Display for FilterExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Group { mode, children } => write!(f, "{mode} ({})", children.len()),
+            Self::Player(_) => write!(f, "Player"),
+            Self::Map(_) => write!(f, "Map"),
+            Self::Combat(_) => write!(f, "Combat"),
+            Self::Mount(_) => write!(f, "Mount"),
+            Self::Gear(_) => write!(f, "Gear"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::PlayerInfo;
+
+    #[test]
+    fn nested() {
+        let in_combat = || FilterExpression::Combat(CombatTrigger::VALUES[1]);
+        let expression = FilterExpression::Group {
+            mode: TriggerMode::Any,
+            children: vec![
+                FilterExpression::Group {
+                    mode: TriggerMode::All,
+                    children: vec![in_combat(), FilterExpression::Mount(Mount::Raptor.into())],
+                },
+                FilterExpression::Group {
+                    mode: TriggerMode::NotAny,
+                    children: vec![in_combat()],
+                },
+            ],
+        };
+
+        let mut ctx = Context::disabled();
+        ctx.player = PlayerInfo::empty();
+        ctx.player.mount = Ok(Mount::None);
+        assert!(expression.is_active(&ctx));
+
+        ctx.ui.combat = true;
+        assert!(!expression.is_active(&ctx));
+
+        ctx.player.mount = Ok(Mount::Raptor);
+        assert!(expression.is_active(&ctx));
+
+        assert!(FilterExpression::default().is_active(&ctx));
+    }
+}
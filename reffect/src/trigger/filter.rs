@@ -1,6 +1,10 @@
-use super::{MapTrigger, PlayerTrigger, map::legacy::MapTriggerLegacy};
+use super::{
+    DayCycleTrigger, FilterExpression, GroupTrigger, MapTrigger, PlayerTrigger, UiTrigger,
+    ZoneTrigger, map::legacy::MapTriggerLegacy,
+};
 use crate::{
     context::{Context, Updateable},
+    render::helper,
     serde::migrate,
 };
 use const_default::ConstDefault;
@@ -18,15 +22,42 @@ pub struct FilterTrigger {
     /// Map configuration.
     #[serde(deserialize_with = "migrate::<_, _, MapTriggerLegacy>")]
     pub map: MapTrigger,
+
+    /// Group configuration.
+    pub group: GroupTrigger,
+
+    /// Zone configuration.
+    pub zone: ZoneTrigger,
+
+    /// Day cycle configuration.
+    pub day_cycle: DayCycleTrigger,
+
+    /// Game UI configuration.
+    pub ui: UiTrigger,
+
+    /// Optional boolean expression.
+    pub expression: Option<FilterExpression>,
 }
 
 impl FilterTrigger {
     pub fn load(&mut self) {
         self.player.load();
+        if let Some(expression) = &mut self.expression {
+            expression.load();
+        }
     }
 
     pub fn is_active(&self, ctx: &Context) -> bool {
-        self.player.is_active(ctx) && self.map.is_active()
+        self.player.is_active(ctx)
+            && self.map.is_active()
+            && self.group.is_active()
+            && self.zone.is_active(ctx)
+            && self.day_cycle.is_active()
+            && self.ui.is_active(ctx)
+            && self
+                .expression
+                .as_ref()
+                .is_none_or(|expression| expression.is_active(ctx))
     }
 
     /// Updates the filter if needed and returns update information.
@@ -41,7 +72,12 @@ impl FilterTrigger {
     }
 
     pub fn allow_child_updates(&self) -> bool {
-        self.player.build.is_active() && self.player.gear.is_active() && self.map.is_active()
+        self.player.build.is_active()
+            && self.player.gear.is_active()
+            && self.player.identity.is_active()
+            && self.map.is_active()
+            && self.group.is_active()
+            && self.day_cycle.is_active()
     }
 
     pub fn render_options(&mut self, ui: &Ui, ctx: &Context) {
@@ -49,28 +85,92 @@ impl FilterTrigger {
 
         ui.spacing();
         self.map.render_options(ui, ctx);
+
+        ui.spacing();
+        self.group.render_options(ui, ctx);
+
+        ui.spacing();
+        self.zone.render_options(ui, ctx);
+
+        ui.spacing();
+        self.day_cycle.render_options(ui, ctx);
+
+        ui.spacing();
+        self.ui.render_options(ui);
+
+        ui.spacing();
+        self.render_expression(ui, ctx);
+    }
+
+    /// Renders the optional expression options.
+    fn render_expression(&mut self, ui: &Ui, ctx: &Context) {
+        let _id = ui.push_id("expression");
+        let mut enabled = self.expression.is_some();
+        if ui.checkbox("Expression", &mut enabled) {
+            self.expression = enabled.then(|| {
+                let mut expression = FilterExpression::default();
+                expression.force_update(ctx);
+                expression
+            });
+        }
+        helper(ui, || {
+            ui.text("Additional nested any of, all of & none of conditions");
+            ui.text("Combines player, map, combat, mount & gear filters");
+        });
+
+        if let Some(expression) = &mut self.expression {
+            expression.render_options(ui, ctx);
+        }
     }
 
-    pub fn render_debug(&mut self, ui: &Ui, _ctx: &Context) {
+    pub fn render_debug(&mut self, ui: &Ui, ctx: &Context) {
         ui.text(format!("Gear filter: {}", self.player.gear.is_active()));
         ui.text(format!("Build filter: {}", self.player.build.is_active()));
+        ui.text(format!(
+            "Identity filter: {}",
+            self.player.identity.is_active()
+        ));
         ui.text(format!("Map filter: {}", self.map.is_active()));
+        ui.text(format!("Group filter: {}", self.group.is_active()));
+        ui.text(format!("Zone filter: {}", self.zone.is_active(ctx)));
+        ui.text(format!("Day cycle filter: {}", self.day_cycle.is_active()));
+        ui.text(format!("UI filter: {}", self.ui.is_active(ctx)));
+        if let Some(expression) = &self.expression {
+            ui.text(format!("Expression filter: {}", expression.is_active(ctx)));
+        }
     }
 }
 
 impl Updateable for FilterTrigger {
     fn needs_update(&self, ctx: &Context) -> bool {
-        self.map.needs_update(ctx) || self.player.needs_update(ctx)
+        self.map.needs_update(ctx)
+            || self.group.needs_update(ctx)
+            || self.day_cycle.needs_update(ctx)
+            || self.player.needs_update(ctx)
+            || self
+                .expression
+                .as_ref()
+                .is_some_and(|expression| expression.needs_update(ctx))
     }
 
     fn force_update(&mut self, ctx: &Context) {
         self.player.force_update(ctx);
         self.map.force_update(ctx);
+        self.group.force_update(ctx);
+        self.day_cycle.force_update(ctx);
+        if let Some(expression) = &mut self.expression {
+            expression.force_update(ctx);
+        }
     }
 
     fn update_if_need(&mut self, ctx: &Context) {
         self.player.update_if_need(ctx);
         self.map.update_if_need(ctx);
+        self.group.update_if_need(ctx);
+        self.day_cycle.update_if_need(ctx);
+        if let Some(expression) = &mut self.expression {
+            expression.update_if_need(ctx);
+        }
     }
 }
 
@@ -0,0 +1,149 @@
+use super::TriggerMode;
+use crate::{
+    context::{Context, GroupType, Update, Updateable},
+    render::{enum_combo_bitflags, helper},
+    serde::bitflags,
+};
+use const_default::ConstDefault;
+use enumflags2::BitFlags;
+use nexus::imgui::{ComboBoxFlags, Ui};
+use serde::{Deserialize, Serialize};
+
+/// Group trigger.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct GroupTrigger {
+    /// Group type.
+    #[serde(with = "bitflags")]
+    #[cfg_attr(feature = "schema", schemars(with = "bitflags::Schema<GroupType>"))]
+    pub group_type: BitFlags<GroupType>,
+
+    #[serde(skip)]
+    active: bool,
+}
+
+impl ConstDefault for GroupTrigger {
+    const DEFAULT: Self = Self {
+        group_type: BitFlags::EMPTY,
+        active: true,
+    };
+}
+
+impl Default for GroupTrigger {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl GroupTrigger {
+    /// Returns whether the group trigger is active.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+impl Updateable for GroupTrigger {
+    fn needs_update(&self, ctx: &Context) -> bool {
+        ctx.has_update_or_edit(Update::GroupIdentity)
+    }
+
+    fn force_update(&mut self, ctx: &Context) {
+        let group_type = ctx.group.as_ref().ok().map(|group| group.group_type);
+        self.active = TriggerMode::Any.check_flags_optional(self.group_type, group_type);
+    }
+}
+
+impl GroupTrigger {
+    /// Renders group trigger options.
+    pub fn render_options(&mut self, ui: &Ui, ctx: &Context) -> bool {
+        let _id = ui.push_id("group");
+
+        let changed = enum_combo_bitflags(
+            ui,
+            "Group Type",
+            &mut self.group_type,
+            ComboBoxFlags::empty(),
+        );
+        helper(ui, || {
+            ui.text("Whether playing solo, in a party or in a squad")
+        });
+
+        if changed {
+            // ensure fresh state after changed
+            self.force_update(ctx);
+        }
+
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        context::GroupInfo,
+        trigger::{ConditionTrigger, ProgressActive},
+    };
+
+    fn create_ctx(group_type: GroupType) -> Context {
+        let mut ctx = Context::disabled();
+        ctx.group = Ok(GroupInfo {
+            group_type,
+            ..GroupInfo::empty()
+        });
+        ctx
+    }
+
+    fn create_trigger(group_type: BitFlags<GroupType>) -> GroupTrigger {
+        GroupTrigger {
+            group_type,
+            ..GroupTrigger::DEFAULT
+        }
+    }
+
+    #[test]
+    fn filter() {
+        let mut solo = create_trigger(GroupType::Solo.into());
+        let mut grouped = create_trigger(GroupType::Party | GroupType::Squad);
+        let mut any = create_trigger(BitFlags::EMPTY);
+
+        let ctx = create_ctx(GroupType::Solo);
+        for trigger in [&mut solo, &mut grouped, &mut any] {
+            trigger.force_update(&ctx);
+        }
+        assert!(solo.is_active());
+        assert!(!grouped.is_active());
+        assert!(any.is_active());
+
+        for group_type in [GroupType::Party, GroupType::Squad] {
+            let ctx = create_ctx(group_type);
+            for trigger in [&mut solo, &mut grouped, &mut any] {
+                trigger.force_update(&ctx);
+            }
+            assert!(!solo.is_active());
+            assert!(grouped.is_active());
+            assert!(any.is_active());
+        }
+
+        // unavailable group info does not filter
+        solo.force_update(&Context::disabled());
+        assert!(solo.is_active());
+    }
+
+    #[test]
+    fn condition() {
+        let active = ProgressActive::empty();
+        let mut condition = ConditionTrigger::Group(create_trigger(GroupType::Squad.into()));
+
+        for (group_type, expected) in [
+            (GroupType::Solo, false),
+            (GroupType::Party, false),
+            (GroupType::Squad, true),
+        ] {
+            let ctx = create_ctx(group_type);
+            condition.force_update(&ctx);
+            assert_eq!(condition.is_active(&ctx, &active), expected);
+        }
+    }
+}
@@ -3,13 +3,16 @@ pub mod legacy;
 use super::TriggerMode;
 use crate::{
     action::Action,
-    context::{Context, MapCategory, Update, Updateable},
-    render::{enum_combo_bitflags, helper, input_u32, item_context_menu, map_select},
+    context::{Context, CustomMapGroup, MapCategory, Update, Updateable},
+    render::{
+        MapSelection, Validation, enum_combo_bitflags, helper, input_u32, item_context_menu,
+        map_select,
+    },
     serde::bitflags,
 };
 use const_default::ConstDefault;
 use enumflags2::BitFlags;
-use nexus::imgui::{ComboBoxFlags, Ui};
+use nexus::imgui::{ComboBoxFlags, Selectable, Ui};
 use serde::{Deserialize, Serialize};
 
 /// Map trigger.
@@ -29,6 +32,10 @@ pub struct MapTrigger {
     #[serde(default)] // TODO: move up after migration end
     pub ids: Vec<u32>,
 
+    /// User-defined map group ids.
+    #[serde(default)]
+    pub groups: Vec<u32>,
+
     #[serde(skip)]
     active: bool,
 }
@@ -42,6 +49,7 @@ impl ConstDefault for MapTrigger {
         category: BitFlags::EMPTY,
         whitelist: true,
         ids: Vec::new(),
+        groups: Vec::new(),
         active: false,
     };
 }
@@ -74,8 +82,9 @@ impl Updateable for MapTrigger {
 
     fn force_update(&mut self, ctx: &Context) {
         self.active = TriggerMode::Any.check_flags(self.category, ctx.map.category)
-            && (self.ids.is_empty() || {
-                let id_match = self.ids.iter().any(|id| ctx.map.is_on_map(*id));
+            && ((self.ids.is_empty() && self.groups.is_empty()) || {
+                let id_match = self.ids.iter().any(|id| ctx.map.is_on_map(*id))
+                    || self.groups.iter().any(|id| ctx.is_on_map_group(*id));
                 if self.whitelist { id_match } else { !id_match }
             });
     }
@@ -112,18 +121,45 @@ impl MapTrigger {
             self.ids.push(0);
             changed = true;
         }
-        item_context_menu("mapaddctx", || {
-            if let Some(maps) = map_select(ui) {
+        item_context_menu("mapaddctx", || match map_select(ui, &ctx.map_groups) {
+            Some(MapSelection::Maps(maps)) => {
                 self.ids.extend(maps.iter().map(|map| map.id));
+                changed = true;
+            }
+            Some(MapSelection::Custom(group)) => {
+                self.groups.push(group.id);
+                changed = true;
             }
+            None => {}
+        });
+        helper(ui, || {
+            ui.text("Right click to insert commonly used maps");
+            ui.text("Custom map groups are managed in the settings");
         });
-        helper(ui, || ui.text("Right click to insert commonly used maps"));
 
         changed |= action.perform(&mut self.ids);
 
+        let mut action = Action::new();
+        for (i, id) in self.groups.iter_mut().enumerate() {
+            let _id = ui.push_id(format!("group{i}"));
+            changed |= action.input_with_buttons(ui, i, || {
+                Self::validate_group(ctx, *id)
+                    .for_item(ui, || Self::group_select(ui, id, &ctx.map_groups))
+            });
+
+            ui.same_line();
+            ui.text(format!("Map Group {}", i + 1));
+        }
+        if !ctx.map_groups.is_empty() && ui.button("Add Map Group") {
+            self.groups.push(ctx.map_groups[0].id);
+            changed = true;
+        }
+
+        changed |= action.perform(&mut self.groups);
+
         changed |= ui.checkbox("Use Whitelist", &mut self.whitelist);
         helper(ui, || {
-            ui.text("Whether to use map ids as whitelist or blacklist")
+            ui.text("Whether to use map ids & groups as whitelist or blacklist")
         });
 
         if changed {
@@ -133,4 +169,68 @@ impl MapTrigger {
 
         changed
     }
+
+    /// Validates the map group id.
+    fn validate_group(ctx: &Context, id: u32) -> Validation<String> {
+        if ctx.map_group(id).is_some() {
+            Validation::Ok
+        } else {
+            Validation::Warn(format!("Map group {id} does not exist"))
+        }
+    }
+
+    /// Renders a map group selection.
+    fn group_select(ui: &Ui, id: &mut u32, groups: &[CustomMapGroup]) -> bool {
+        let mut changed = false;
+        let preview = groups
+            .iter()
+            .find(|group| group.id == *id)
+            .map_or("Unknown", |group| group.name.as_str());
+        if let Some(_token) = ui.begin_combo("##group", preview) {
+            for (i, group) in groups.iter().enumerate() {
+                let _id = ui.push_id(i as i32);
+                let selected = group.id == *id;
+                if Selectable::new(&group.name).selected(selected).build(ui) {
+                    *id = group.id;
+                    changed = true;
+                }
+                if selected {
+                    ui.set_item_default_focus();
+                }
+            }
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_group() {
+        let mut ctx = Context::disabled();
+        ctx.map.id = 1206;
+        let id = ctx.next_map_group_id();
+        let mut group = CustomMapGroup::new(id, "Mistlock");
+        group.ids.push(1206);
+        ctx.map_groups.push(group);
+
+        let mut trigger = MapTrigger {
+            groups: vec![id],
+            ..MapTrigger::default()
+        };
+        trigger.force_update(&ctx);
+        assert!(trigger.is_active());
+
+        // renaming keeps the reference intact
+        ctx.map_groups[0].name = "Mistlock Sanctuary".into();
+        trigger.force_update(&ctx);
+        assert!(trigger.is_active());
+
+        assert_eq!(ctx.next_map_group_id(), id + 1);
+        ctx.map_groups.clear();
+        trigger.force_update(&ctx);
+        assert!(!trigger.is_active());
+    }
 }
@@ -1,9 +1,18 @@
 mod ability;
+mod change;
 mod condition;
+mod day_cycle;
+mod expression;
 mod filter;
+mod group;
 mod map;
 mod mode;
 mod player;
 mod progress;
+mod ui;
+mod zone;
 
-pub use self::{ability::*, condition::*, filter::*, map::*, mode::*, player::*, progress::*};
+pub use self::{
+    ability::*, change::*, condition::*, day_cycle::*, expression::*, filter::*, group::*, map::*,
+    mode::*, player::*, progress::*, ui::*, zone::*,
+};
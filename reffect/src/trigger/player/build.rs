@@ -1,15 +1,22 @@
 use super::TraitRequirement;
 use crate::{
     action::Action,
-    context::{Context, ProfSelection, Profession, Specialization, Update, Updateable},
-    render::{enum_combo_bitflags, helper, input_skill_id, input_trait_id},
+    chatlink::{BuildTemplate, decode_build},
+    colors,
+    context::{Context, ProfSelection, Profession, SpecLine, Specialization, Update, Updateable},
+    internal::{Interface, Internal},
+    render::{
+        Validation, enum_combo_bitflags, helper, input_skill_id, input_trait_id, input_u32,
+        item_context_menu,
+    },
     serde::bitflags,
     trigger::TriggerMode,
 };
 use const_default::ConstDefault;
 use enumflags2::BitFlags;
-use nexus::imgui::{ComboBoxFlags, InputTextFlags, Ui};
+use nexus::imgui::{ComboBoxFlags, InputTextFlags, MenuItem, Ui};
 use serde::{Deserialize, Serialize};
+use strum::VariantArray;
 
 /// Player build trigger.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -36,6 +43,12 @@ pub struct BuildTrigger {
     )]
     pub specs: BitFlags<Specialization>,
 
+    /// Specialization lines.
+    pub spec_lines: Vec<u32>,
+
+    /// Trigger logic mode for specialization lines.
+    pub spec_line_mode: TriggerMode,
+
     /// Selected skills.
     pub skill_selections: Vec<u32>,
 
@@ -55,6 +68,10 @@ pub struct BuildTrigger {
 
     #[serde(skip)]
     active: bool,
+
+    /// Whether traits of the last imported build were left unchanged.
+    #[serde(skip)]
+    traits_skipped: bool,
 }
 
 impl BuildTrigger {
@@ -69,6 +86,37 @@ impl BuildTrigger {
         }
     }
 
+    /// Imports traits, specializations, specialization lines, profession selections and pets from a build template.
+    ///
+    /// The template only contains trait positions, which are resolved via the major traits of each line.
+    /// Traits are left unchanged and `false` is returned if the major traits of any line were unavailable.
+    pub fn import_template(
+        &mut self,
+        template: &BuildTemplate,
+        major_traits: impl Fn(u32) -> Option<[u32; 9]>,
+    ) -> bool {
+        let traits = template
+            .lines
+            .iter()
+            .filter(|line| line.id != 0)
+            .map(|line| major_traits(line.id).map(|major| line.selected_traits(major)))
+            .collect::<Option<Vec<_>>>();
+        let resolved = traits.is_some();
+        if let Some(traits) = traits {
+            self.traits = traits
+                .into_iter()
+                .flatten()
+                .map(|id| TraitRequirement { id, present: true })
+                .collect();
+        }
+
+        self.specs = template.specialization().into();
+        self.spec_lines = template.spec_lines().collect();
+        self.prof_selections = template.prof_selections();
+        self.pets = template.pets().collect();
+        resolved
+    }
+
     /// Returns whether the build trigger is active.
     pub fn is_active(&self) -> bool {
         self.active
@@ -79,6 +127,16 @@ impl BuildTrigger {
         TriggerMode::Any.check_flags_optional(self.specs, ctx.player.spec.ok())
     }
 
+    /// Resolves whether specialization lines match.
+    fn spec_lines_active(&self, ctx: &Context) -> bool {
+        if let Ok(build) = ctx.player.build.as_ref() {
+            self.spec_line_mode
+                .check_slice(&self.spec_lines, |id| build.specs.contains(id))
+        } else {
+            true
+        }
+    }
+
     /// Resolves whether traits match.
     fn traits_active(&self, ctx: &Context) -> bool {
         if let Ok(build) = ctx.player.build.as_ref() {
@@ -122,6 +180,81 @@ impl BuildTrigger {
         }
     }
 
+    /// Validates the specialization line id.
+    fn spec_line_validate(ctx: &Context, id: u32) -> Validation<String> {
+        match SpecLine::find(id) {
+            Some(line) => match ctx.player.prof {
+                Ok(prof) if prof != line.prof => {
+                    Validation::Warn(format!("{} is a {} specialization", line.name, line.prof))
+                }
+                _ => Validation::Confirm(format!("{} ({})", line.name, line.prof)),
+            },
+            None => Validation::Error(format!("Id {id} is no specialization")),
+        }
+    }
+
+    /// Renders a specialization line selection menu grouped by profession.
+    fn spec_line_menu(ui: &Ui, id: &mut u32) -> bool {
+        let mut changed = false;
+        for prof in Profession::VARIANTS {
+            ui.menu(prof.to_string(), || {
+                for line in SpecLine::for_prof(*prof) {
+                    if MenuItem::new(line.name).selected(line.id == *id).build(ui) {
+                        *id = line.id;
+                        changed = true;
+                    }
+                }
+            });
+        }
+        changed
+    }
+
+    /// Renders specialization line options.
+    fn render_spec_line_options(&mut self, ui: &Ui, ctx: &Context) -> bool {
+        let _id = ui.push_id("specline");
+        let mut changed = false;
+
+        changed |= self.spec_line_mode.render_options(ui, "Line Mode");
+
+        let mut action = Action::new();
+        for (i, id) in self.spec_lines.iter_mut().enumerate() {
+            let _id = ui.push_id(i as i32);
+            changed |= action.input_with_buttons(ui, i, || {
+                let mut changed = Self::spec_line_validate(ctx, *id)
+                    .for_item(ui, || input_u32(ui, "##id", id, 0, 0));
+                item_context_menu("##lineselect", || {
+                    changed |= Self::spec_line_menu(ui, id);
+                });
+                changed
+            });
+            ui.same_line();
+            ui.text(format!("Line Id {}", i + 1));
+
+            if i == 0 {
+                helper(ui, || {
+                    ui.text("Specialization (trait) line in build, core or elite");
+                    ui.text("Same as in GW2 API");
+                    ui.text("Right click to select from a list");
+                });
+            }
+        }
+        changed |= action.perform(&mut self.spec_lines);
+
+        if ui.button("Add Line") {
+            self.spec_lines.push(0);
+            changed = true;
+        }
+        item_context_menu("##addline", || {
+            let mut id = 0;
+            if Self::spec_line_menu(ui, &mut id) {
+                self.spec_lines.push(id);
+                changed = true;
+            }
+        });
+
+        changed
+    }
+
     /// Renders trait options.
     fn render_trait_options(&mut self, ui: &Ui) -> bool {
         let _id = ui.push_id("trait");
@@ -236,6 +369,27 @@ impl BuildTrigger {
         let _id = ui.push_id("build");
         let mut changed = false;
 
+        if ui.button("Import from build link")
+            && let Some(template) = ui
+                .clipboard_text()
+                .and_then(|text| decode_build(text.trim()))
+        {
+            self.traits_skipped =
+                !self.import_template(&template, |spec| Internal::get_major_traits(spec).ok());
+            if self.traits_skipped {
+                log::warn!("Failed to resolve traits of imported build");
+            }
+            changed = true;
+        }
+        if self.traits_skipped {
+            ui.text_colored(colors::YELLOW, "Traits unchanged, trait data unavailable");
+        }
+        helper(ui, || {
+            ui.text("Copy a build template chat link ingame first");
+            ui.text("Replaces traits, specializations, lines, prof selections and pets");
+            ui.text("Traits are only imported when trait data is available");
+        });
+
         changed |= enum_combo_bitflags(
             ui,
             "Specialization",
@@ -243,6 +397,8 @@ impl BuildTrigger {
             ComboBoxFlags::HEIGHT_LARGE,
         );
 
+        changed |= self.render_spec_line_options(ui, ctx);
+
         changed |= self.render_trait_options(ui);
 
         changed |= self.render_skill_options(ui);
@@ -272,6 +428,7 @@ impl Updateable for BuildTrigger {
 
     fn force_update(&mut self, ctx: &Context) {
         self.active = self.specs_active(ctx)
+            && self.spec_lines_active(ctx)
             && self.traits_active(ctx)
             && self.skill_selections_active(ctx)
             && self.prof_selections_active(ctx)
@@ -285,12 +442,15 @@ impl ConstDefault for BuildTrigger {
         trait_mode: TriggerMode::All,
         profs: BitFlags::EMPTY,
         specs: BitFlags::EMPTY,
+        spec_lines: Vec::new(),
+        spec_line_mode: TriggerMode::All,
         skill_selections: Vec::new(),
         skill_selections_mode: TriggerMode::All,
         prof_selections: BitFlags::EMPTY,
         pets: Vec::new(),
         pet_mode: TriggerMode::All,
         active: false,
+        traits_skipped: false,
     };
 }
 
@@ -336,4 +496,43 @@ mod tests {
             Specialization::Dragonhunter | Specialization::Reaper
         );
     }
+
+    #[test]
+    fn import_template() {
+        let template = decode_build(
+            "[&DQQIGR4fNxXJMMkwzDDMMM4wzjDSMNIw0TDRMDsuOy4AAAAAAAAAAAAAAAACBQAjAAEAGQEA]",
+        )
+        .expect("failed to decode build");
+
+        // fake major traits encoding line id, tier and position
+        let major_traits = |spec: u32| {
+            (spec != 55).then(|| {
+                std::array::from_fn(|i| 100 * spec + 10 * (i as u32 / 3) + i as u32 % 3 + 1)
+            })
+        };
+
+        let mut trigger = BuildTrigger::default();
+        let all_traits = |spec| Some(major_traits(spec).unwrap_or_default());
+        assert!(trigger.import_template(&template, all_traits));
+        assert_eq!(
+            trigger.traits[..6]
+                .iter()
+                .map(|req| req.id)
+                .collect::<Vec<_>>(),
+            [801, 812, 821, 3003, 3013, 3021]
+        );
+        assert!(trigger.traits.iter().all(|req| req.present));
+
+        // unresolved lines keep existing traits
+        trigger.traits = vec![TraitRequirement {
+            id: 1,
+            present: false,
+        }];
+        assert!(!trigger.import_template(&template, major_traits));
+        assert_eq!(trigger.traits.len(), 1);
+        assert_eq!(trigger.specs, BitFlags::from(Specialization::Soulbeast));
+        assert_eq!(trigger.spec_lines, [8, 30, 55]);
+        assert_eq!(trigger.prof_selections, BitFlags::empty());
+        assert_eq!(trigger.pets, [59, 46]);
+    }
 }
@@ -0,0 +1,114 @@
+use crate::{
+    action::Action,
+    context::{Context, Race, Update, Updateable},
+    render::{enum_combo_bitflags, helper},
+    serde::bitflags,
+    trigger::TriggerMode,
+};
+use const_default::ConstDefault;
+use enumflags2::BitFlags;
+use nexus::imgui::{ComboBoxFlags, Ui};
+use serde::{Deserialize, Serialize};
+
+/// Player identity trigger.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct IdentityTrigger {
+    /// Character races.
+    #[serde(with = "bitflags")]
+    #[cfg_attr(feature = "schema", schemars(with = "bitflags::Schema<Race>"))]
+    pub races: BitFlags<Race>,
+
+    /// Character names.
+    pub characters: Vec<String>,
+
+    /// Whether to use character names as whitelist or blacklist.
+    pub character_whitelist: bool,
+
+    #[serde(skip)]
+    active: bool,
+}
+
+impl ConstDefault for IdentityTrigger {
+    const DEFAULT: Self = Self {
+        races: BitFlags::EMPTY,
+        characters: Vec::new(),
+        character_whitelist: true,
+        active: true,
+    };
+}
+
+impl Default for IdentityTrigger {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl IdentityTrigger {
+    /// Returns whether the identity trigger is active.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Resolves whether the character name matches.
+    fn characters_active(&self, ctx: &Context) -> bool {
+        self.characters.is_empty() || {
+            let name_match = self.characters.contains(&ctx.player.name);
+            if self.character_whitelist {
+                name_match
+            } else {
+                !name_match
+            }
+        }
+    }
+
+    /// Renders identity trigger options.
+    pub fn render_options(&mut self, ui: &Ui, ctx: &Context) -> bool {
+        let _id = ui.push_id("identity");
+        let mut changed = false;
+
+        changed |= enum_combo_bitflags(ui, "Race", &mut self.races, ComboBoxFlags::empty());
+
+        let mut action = Action::new();
+        for (i, name) in self.characters.iter_mut().enumerate() {
+            let _id = ui.push_id(i as i32);
+            changed |= action.input_with_buttons(ui, i, || ui.input_text("##name", name).build());
+
+            ui.same_line();
+            ui.text(format!("Character {}", i + 1));
+        }
+        if ui.button("Add Character") {
+            self.characters.push(ctx.player.name.clone());
+            changed = true;
+        }
+        helper(ui, || ui.text("Adds the current character by default"));
+
+        changed |= action.perform(&mut self.characters);
+
+        if !self.characters.is_empty() {
+            changed |= ui.checkbox("Use Character Whitelist", &mut self.character_whitelist);
+            helper(ui, || {
+                ui.text("Whether to use character names as whitelist or blacklist")
+            });
+        }
+
+        if changed {
+            // ensure fresh state after changed
+            self.force_update(ctx);
+        }
+
+        changed
+    }
+}
+
+impl Updateable for IdentityTrigger {
+    fn needs_update(&self, ctx: &Context) -> bool {
+        ctx.has_update_or_edit(Update::PlayerIdentity)
+    }
+
+    fn force_update(&mut self, ctx: &Context) {
+        self.active = TriggerMode::Any.check_flags_optional(self.races, ctx.player.race.ok())
+            && self.characters_active(ctx);
+    }
+}
@@ -1,10 +1,11 @@
 mod build;
 mod combat;
 mod gear;
+mod identity;
 mod item;
 mod traits;
 
-pub use self::{build::*, combat::*, gear::*, item::*, traits::*};
+pub use self::{build::*, combat::*, gear::*, identity::*, item::*, traits::*};
 
 use super::TriggerMode;
 use crate::{
@@ -32,6 +33,10 @@ pub struct PlayerTrigger {
     #[serde(flatten)]
     pub build: BuildTrigger,
 
+    /// Identity configuration.
+    #[serde(flatten)]
+    pub identity: IdentityTrigger,
+
     /// Current mount.
     #[serde(with = "bitflags")]
     #[cfg_attr(feature = "schema", schemars(with = "bitflags::Schema<Mount>"))]
@@ -43,6 +48,7 @@ impl ConstDefault for PlayerTrigger {
         combat: CombatTrigger::DEFAULT,
         gear: GearTrigger::DEFAULT,
         build: BuildTrigger::DEFAULT,
+        identity: IdentityTrigger::DEFAULT,
         mounts: BitFlags::EMPTY,
     };
 }
@@ -61,6 +67,7 @@ impl PlayerTrigger {
     pub fn is_active(&self, ctx: &Context) -> bool {
         self.build.is_active()
             && self.gear.is_active()
+            && self.identity.is_active()
             && self.combat.is_active(ctx)
             && self.mounts_active(ctx)
     }
@@ -72,17 +79,21 @@ impl PlayerTrigger {
 
 impl Updateable for PlayerTrigger {
     fn needs_update(&self, ctx: &Context) -> bool {
-        self.gear.needs_update(ctx) || self.build.needs_update(ctx)
+        self.gear.needs_update(ctx)
+            || self.build.needs_update(ctx)
+            || self.identity.needs_update(ctx)
     }
 
     fn force_update(&mut self, ctx: &Context) {
         self.build.force_update(ctx);
         self.gear.force_update(ctx);
+        self.identity.force_update(ctx);
     }
 
     fn update_if_need(&mut self, ctx: &Context) {
         self.build.update_if_need(ctx);
         self.gear.update_if_need(ctx);
+        self.identity.update_if_need(ctx);
     }
 }
 
@@ -96,6 +107,9 @@ impl PlayerTrigger {
         ui.spacing();
         self.build.render_options(ui, ctx);
 
+        ui.spacing();
+        self.identity.render_options(ui, ctx);
+
         ui.spacing();
         enum_combo_bitflags(ui, "Mount", &mut self.mounts, ComboBoxFlags::HEIGHT_LARGE);
     }
@@ -98,6 +98,16 @@ impl ProgressActive {
         }
     }
 
+    /// Creates new timed active progress from a remaining time.
+    pub const fn from_remaining(stacks: u32, duration: u32, remaining: u32, now: u32) -> Self {
+        Self::Buff {
+            id: 0,
+            stacks,
+            duration,
+            end: now.saturating_add(remaining),
+        }
+    }
+
     /// Creates new timed active progress from an ability.
     pub fn from_ability(ability: &Ability) -> Self {
         let Ability {
@@ -1,11 +1,18 @@
 mod active;
 mod source;
 mod threshold;
+mod timing;
 mod value;
 
-pub use self::{active::*, source::*, threshold::*, value::*};
+pub use self::{active::*, source::*, threshold::*, timing::*, value::*};
 
-use crate::{context::Context, render::debug_optional, serde::migrate};
+use crate::{
+    colors,
+    context::Context,
+    id::Id,
+    render::{debug_optional, helper, name_combo},
+    serde::migrate,
+};
 use const_default::ConstDefault;
 use nexus::imgui::Ui;
 use serde::{Deserialize, Serialize};
@@ -25,9 +32,27 @@ pub struct ProgressTrigger {
     #[serde(deserialize_with = "migrate::<_, _, ThresholdType>")]
     pub threshold: ProgressThreshold,
 
+    /// Timing options.
+    pub timing: ProgressTiming,
+
+    /// Name of the timer to start when the trigger becomes active.
+    pub start_timer: String,
+
     /// Memoized active progress.
     #[serde(skip)]
     active: Option<ProgressActive>,
+
+    /// Memoized timing state.
+    #[serde(skip)]
+    timing_state: TimingState,
+
+    /// Bound group member index.
+    #[serde(skip)]
+    member: Option<usize>,
+
+    /// Bound reference target element.
+    #[serde(skip)]
+    reference: Option<Id>,
 }
 
 impl ProgressTrigger {
@@ -36,7 +61,12 @@ impl ProgressTrigger {
         Self {
             source,
             threshold: ProgressThreshold::DEFAULT,
+            timing: ProgressTiming::DEFAULT,
+            start_timer: String::new(),
             active: None,
+            timing_state: TimingState::DEFAULT,
+            member: None,
+            reference: None,
         }
     }
 
@@ -51,7 +81,12 @@ impl ProgressTrigger {
                 threshold_type: ThresholdType::Present,
                 amount_type: AmountType::default(),
             },
+            timing: ProgressTiming::DEFAULT,
+            start_timer: String::new(),
             active: None,
+            timing_state: TimingState::DEFAULT,
+            member: None,
+            reference: None,
         }
     }
 
@@ -60,6 +95,44 @@ impl ProgressTrigger {
         self.active.as_ref()
     }
 
+    /// Returns the bound group member index.
+    pub fn member(&self) -> Option<usize> {
+        self.member
+    }
+
+    /// Binds the trigger to the given group member index.
+    pub fn bind_member(&mut self, member: Option<usize>) {
+        self.member = member;
+    }
+
+    /// Returns the bound reference target element.
+    pub fn reference(&self) -> Option<Id> {
+        self.reference
+    }
+
+    /// Binds the trigger to the given reference target element.
+    pub fn bind_reference(&mut self, target: Option<Id>) {
+        self.reference = target;
+    }
+
+    /// Checks whether the trigger is waiting for timings to pass.
+    pub fn is_pending(&self) -> bool {
+        self.timing_state.is_pending()
+    }
+
+    /// Checks whether the trigger gained or lost activity within the given duration.
+    pub fn changed_within(&self, now: u32, duration: u32) -> bool {
+        self.timing_state
+            .last_edge()
+            .is_some_and(|(_, time)| now.saturating_sub(time) < duration)
+    }
+
+    /// Returns the timer to start if the trigger became active at the given time.
+    pub fn timer_to_start(&self, now: u32) -> Option<&str> {
+        let gained = self.timing_state.last_edge() == Some((Edge::Gain, now));
+        (gained && !self.start_timer.is_empty()).then_some(self.start_timer.as_str())
+    }
+
     /// Checks whether the element is visible (trigger is active).
     pub fn is_visible(&self) -> bool {
         self.active.is_some()
@@ -74,7 +147,7 @@ impl ProgressTrigger {
         } else {
             self.source.update_on()
         };
-        ctx.has_update_or_edit(updates)
+        ctx.has_update_or_edit(updates) || self.is_pending()
     }
 
     /// Updates the trigger if forced or needed.
@@ -86,8 +159,19 @@ impl ProgressTrigger {
 
     /// Force updates the trigger.
     pub fn force_update(&mut self, ctx: &Context, parent: Option<&Self>) {
+        self.force_update_with(ctx, parent.and_then(|trigger| trigger.active()));
+    }
+
+    /// Force updates the trigger with the given inherited or referenced progress.
+    pub fn force_update_with(&mut self, ctx: &Context, inherited: Option<&ProgressActive>) {
         // TODO: end of edit causes memo to "flash", maybe flag to end edit mode?
-        self.active = self.resolve_active(ctx, parent.and_then(|trigger| trigger.active()));
+        let active = self.resolve_active(ctx, inherited);
+        self.active = if ctx.edit.is_editing() {
+            self.timing_state.reset();
+            active
+        } else {
+            self.timing_state.apply(&self.timing, active, ctx.now)
+        };
     }
 
     /// Resolves the current [`ProgressActive`].
@@ -100,25 +184,39 @@ impl ProgressTrigger {
             Some(self.source.progress_edit(ctx, parent))
         } else {
             self.source
-                .progress(ctx, parent)
+                .progress(ctx, parent, self.member)
                 .filter(|active| self.source.no_threshold() || self.threshold.is_met(active, ctx))
         }
     }
 
     /// Renders the trigger options.
-    pub fn render_options(&mut self, ui: &Ui, ctx: &Context) {
+    pub fn render_options(&mut self, ui: &Ui, ctx: &Context) -> bool {
         let mut changed = false;
         let _id = ui.push_id("trigger");
 
-        changed |= self.source.render_options(ui);
+        changed |= self.source.render_options(ui, ctx);
+        if self.source.reference().is_some() && self.reference.is_none() {
+            ui.text_colored(colors::RED, "Target not found or cyclic reference");
+        }
 
         if !self.source.no_threshold() {
             changed |= self.threshold.render_options(ui);
+            changed |= self.timing.render_options(ui);
+        }
+
+        if !ctx.timers.is_empty() || !self.start_timer.is_empty() {
+            let timers = ctx.timers.iter().map(|timer| timer.name.as_str());
+            changed |= name_combo(ui, "Start Timer", &mut self.start_timer, timers, true);
+            helper(ui, || {
+                ui.text("Timer to start or restart when the trigger becomes active");
+                ui.text("Timers are managed in the settings");
+            });
         }
 
         if changed {
             self.force_update(ctx, None);
         }
+        changed
     }
 
     pub fn render_debug(&mut self, ui: &Ui) {
@@ -131,7 +229,12 @@ impl Clone for ProgressTrigger {
         Self {
             source: self.source.clone(),
             threshold: self.threshold.clone(),
+            timing: self.timing.clone(),
+            start_timer: self.start_timer.clone(),
             active: None, // dont clone the memo
+            timing_state: TimingState::DEFAULT,
+            member: self.member,
+            reference: self.reference,
         }
     }
 }
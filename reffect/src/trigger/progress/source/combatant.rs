@@ -1,5 +1,5 @@
 use crate::{
-    context::{BuffMap, CombatantResources, Context, SkillInfo, Visibility},
+    context::{BuffMap, CombatantResources, Context, GroupMember, SkillInfo, Visibility},
     error::Error,
     internal::{Interface, Internal},
     render::{Validation, enum_combo, helper},
@@ -54,6 +54,10 @@ pub enum Combatant {
     /// Group member.
     #[strum(serialize = "Group Member 4")]
     GroupMember4,
+
+    /// Group member of the surrounding repeater.
+    #[strum(serialize = "Repeater Member")]
+    RepeaterMember,
 }
 
 impl Default for Combatant {
@@ -67,29 +71,55 @@ impl ConstDefault for Combatant {
 }
 
 impl Combatant {
+    /// Returns the group member for the combatant.
+    ///
+    /// Numbered group members refer to the own party or squad subgroup.
+    /// The member index is bound by the surrounding repeater, if any.
+    pub fn group_member<'ctx>(
+        &self,
+        ctx: &'ctx Context,
+        member: Option<usize>,
+    ) -> Option<&'ctx GroupMember> {
+        let group = ctx.group.as_ref().ok()?;
+        match self {
+            Self::Player | Self::Pet | Self::Target => None,
+            Self::GroupMember1 => group.party_member(0),
+            Self::GroupMember2 => group.party_member(1),
+            Self::GroupMember3 => group.party_member(2),
+            Self::GroupMember4 => group.party_member(3),
+            Self::RepeaterMember => group.members.get(member?),
+        }
+    }
+
     /// Returns the combatant buffs.
-    pub fn buffs<'ctx>(&self, ctx: &'ctx Context) -> Option<&'ctx BuffMap> {
+    pub fn buffs<'ctx>(&self, ctx: &'ctx Context, member: Option<usize>) -> Option<&'ctx BuffMap> {
         match self {
             Self::Player => Some(&ctx.player.buff_info.as_ref().ok()?.buffs),
             Self::Pet => None,
             Self::Target => ctx.target.buffs.as_ref().ok(),
-            Self::GroupMember1 => ctx.group.as_ref().ok()?.members[0].buffs.as_ref().ok(),
-            Self::GroupMember2 => ctx.group.as_ref().ok()?.members[1].buffs.as_ref().ok(),
-            Self::GroupMember3 => ctx.group.as_ref().ok()?.members[2].buffs.as_ref().ok(),
-            Self::GroupMember4 => ctx.group.as_ref().ok()?.members[3].buffs.as_ref().ok(),
+            Self::GroupMember1
+            | Self::GroupMember2
+            | Self::GroupMember3
+            | Self::GroupMember4
+            | Self::RepeaterMember => self.group_member(ctx, member)?.buffs.as_ref().ok(),
         }
     }
 
     /// Returns the combatant resources.
-    pub fn resources<'ctx>(&self, ctx: &'ctx Context) -> Option<&'ctx CombatantResources> {
+    pub fn resources<'ctx>(
+        &self,
+        ctx: &'ctx Context,
+        member: Option<usize>,
+    ) -> Option<&'ctx CombatantResources> {
         match self {
             Self::Player => Some(&ctx.player.resources.as_ref().ok()?.combatant),
             Self::Pet => ctx.player.resources.as_ref().ok()?.pet.as_ref(),
             Self::Target => ctx.target.resources.as_ref().ok(),
-            Self::GroupMember1 => ctx.group.as_ref().ok()?.members[0].resources.as_ref().ok(),
-            Self::GroupMember2 => ctx.group.as_ref().ok()?.members[1].resources.as_ref().ok(),
-            Self::GroupMember3 => ctx.group.as_ref().ok()?.members[2].resources.as_ref().ok(),
-            Self::GroupMember4 => ctx.group.as_ref().ok()?.members[3].resources.as_ref().ok(),
+            Self::GroupMember1
+            | Self::GroupMember2
+            | Self::GroupMember3
+            | Self::GroupMember4
+            | Self::RepeaterMember => self.group_member(ctx, member)?.resources.as_ref().ok(),
         }
     }
 
@@ -98,7 +128,11 @@ impl Combatant {
         match self {
             Self::Player | Self::Target => Validation::Ok,
             Self::Pet => Validation::Error("Pet does not support effects"),
-            Self::GroupMember1 | Self::GroupMember2 | Self::GroupMember3 | Self::GroupMember4 => {
+            Self::GroupMember1
+            | Self::GroupMember2
+            | Self::GroupMember3
+            | Self::GroupMember4
+            | Self::RepeaterMember => {
                 Validation::Warn("Group member only supports boon & condition effects")
             }
         }
@@ -112,9 +146,11 @@ impl Combatant {
             Self::Target => {
                 visibility.intersects(Visibility::TargetNonHostile | Visibility::TargetHostile)
             }
-            Self::GroupMember1 | Self::GroupMember2 | Self::GroupMember3 | Self::GroupMember4 => {
-                visibility.intersects(Visibility::Group)
-            }
+            Self::GroupMember1
+            | Self::GroupMember2
+            | Self::GroupMember3
+            | Self::GroupMember4
+            | Self::RepeaterMember => visibility.intersects(Visibility::Group),
         }
     }
 
@@ -143,7 +179,11 @@ impl Combatant {
         match self {
             Self::Player | Self::Pet => Validation::Ok,
             Self::Target => Validation::Warn("Target only supports normalized health/barrier"),
-            Self::GroupMember1 | Self::GroupMember2 | Self::GroupMember3 | Self::GroupMember4 => {
+            Self::GroupMember1
+            | Self::GroupMember2
+            | Self::GroupMember3
+            | Self::GroupMember4
+            | Self::RepeaterMember => {
                 Validation::Warn("Group member only supports normalized health/barrier")
             }
         }
@@ -154,9 +194,11 @@ impl Combatant {
         match self {
             Self::Player | Self::Target => Validation::Ok,
             Self::Pet => Validation::Error("Pet does not support defiance"),
-            Self::GroupMember1 | Self::GroupMember2 | Self::GroupMember3 | Self::GroupMember4 => {
-                Validation::Error("Group member does not support defiance")
-            }
+            Self::GroupMember1
+            | Self::GroupMember2
+            | Self::GroupMember3
+            | Self::GroupMember4
+            | Self::RepeaterMember => Validation::Error("Group member does not support defiance"),
         }
     }
 
@@ -173,6 +215,8 @@ impl Combatant {
             ui.text("Combatant to use");
             ui.text("Player: controlled character");
             ui.text("Pet: Ranger pet or Mechanist mech");
+            ui.text("Group Member: member of own party or squad subgroup");
+            ui.text("Repeater Member: current member of the surrounding repeater");
         });
 
         changed
@@ -0,0 +1,30 @@
+use crate::internal::{Interface, Internal};
+use std::{cell::RefCell, collections::HashMap};
+
+// lookups only happen on the render thread
+thread_local! { static CACHE: RefCell<HashMap<u32, u32>> = RefCell::new(HashMap::new()); }
+
+/// Returns the item applying the buff, if any.
+///
+/// Successful lookups via the internal API are cached, failed lookups are retried.
+pub fn buff_item(buff: u32) -> Option<u32> {
+    CACHE.with_borrow_mut(|cache| match cache.get(&buff) {
+        Some(item) => Some(*item),
+        None => {
+            let item = Internal::get_item_from_buff(buff).ok()?;
+            cache.insert(buff, item);
+            Some(item)
+        }
+    })
+}
+
+/// Returns the sorted ids of the buffs applied by any of the items.
+pub fn consumable_buffs<'a>(buffs: impl IntoIterator<Item = &'a u32>, items: &[u32]) -> Vec<u32> {
+    let mut ids = buffs
+        .into_iter()
+        .copied()
+        .filter(|buff| buff_item(*buff).is_some_and(|item| items.contains(&item)))
+        .collect::<Vec<_>>();
+    ids.sort_unstable();
+    ids
+}
@@ -1,4 +1,5 @@
 mod combatant;
+mod consumable;
 mod legacy;
 mod transfer;
 
@@ -8,16 +9,24 @@ use self::transfer::Transfer;
 use super::ProgressActive;
 use crate::{
     action::Action,
-    context::{Buff, Context, ResourceType, SkillInfo, Slot, Update, Updates},
+    context::{
+        Buff, BuffMap, Category, Context, DayCycle, ResourceType, ScheduleState, ScheduledEvent,
+        SkillInfo, Slot, Stacking, Update, Updates,
+    },
     enums::check_variant_array,
     error::Error,
     internal::{Interface, Internal},
-    render::{Validation, enum_combo, helper, input_skill_id},
+    render::{
+        Validation, enum_combo, enum_combo_bitflags, helper, input_item_id,
+        input_positive_with_format, input_skill_id, item_context_menu,
+    },
+    serde::bitflags,
 };
 use const_default::ConstDefault;
+use enumflags2::{BitFlags, make_bitflags};
 use nexus::{
     gamebind::GameBind,
-    imgui::{ComboBoxFlags, InputTextFlags, Ui},
+    imgui::{ComboBoxFlags, InputTextFlags, MenuItem, Selectable, Ui},
 };
 use serde::{Deserialize, Serialize};
 use strum::{AsRefStr, EnumCount, EnumIter, IntoStaticStr, VariantArray};
@@ -35,6 +44,12 @@ pub enum ProgressSource {
     /// Inherit from above.
     Inherit,
 
+    /// Reference to another element in the same pack by key or name path.
+    Reference {
+        #[serde(default)]
+        target: String,
+    },
+
     /// Always active, no associated progress.
     #[serde(alias = "None")]
     Always,
@@ -54,6 +69,30 @@ pub enum ProgressSource {
         ids: Vec<u32>,
     },
 
+    /// Buffs matching the filters, counts distinct matches.
+    #[strum(serialize = "Effect Category")]
+    BuffCategory {
+        #[serde(default)]
+        combatant: Combatant,
+
+        #[serde(default, with = "bitflags")]
+        #[cfg_attr(feature = "schema", schemars(with = "bitflags::Schema<Category>"))]
+        categories: BitFlags<Category>,
+
+        #[serde(default, with = "bitflags")]
+        #[cfg_attr(feature = "schema", schemars(with = "bitflags::Schema<Stacking>"))]
+        stacking: BitFlags<Stacking>,
+
+        #[serde(default)]
+        excluded: Vec<u32>,
+    },
+
+    /// Consumable item ids, multiple matches are merged.
+    Consumable {
+        #[serde(default)]
+        items: Vec<u32>,
+    },
+
     /// Ability ids, first match is used.
     #[strum(serialize = "Ability Recharge")]
     Ability {
@@ -105,6 +144,43 @@ pub enum ProgressSource {
     /// Profession resource rate.
     #[strum(serialize = "Resource Rate")]
     ResourceRate,
+
+    /// Day & night cycle phase.
+    #[strum(serialize = "Day Cycle")]
+    DayCycle {
+        #[serde(default, with = "bitflags")]
+        #[cfg_attr(feature = "schema", schemars(with = "bitflags::Schema<DayCycle>"))]
+        phases: BitFlags<DayCycle>,
+    },
+
+    /// Scheduled event by name.
+    #[strum(serialize = "Scheduled Event")]
+    Event {
+        #[serde(default)]
+        name: String,
+    },
+
+    /// User-defined timer by name.
+    #[strum(serialize = "Custom Timer")]
+    Timer {
+        #[serde(default)]
+        name: String,
+    },
+
+    /// External variable by name.
+    #[strum(serialize = "External Variable")]
+    Variable {
+        #[serde(default)]
+        name: String,
+
+        /// Maximum value for untimed variables.
+        #[serde(default = "default_variable_max")]
+        max: f32,
+    },
+}
+
+fn default_variable_max() -> f32 {
+    100.0
 }
 
 impl ConstDefault for ProgressSource {
@@ -120,11 +196,21 @@ impl Default for ProgressSource {
 impl VariantArray for ProgressSource {
     const VARIANTS: &'static [Self] = &[
         Self::Inherit,
+        Self::Reference {
+            target: String::new(),
+        },
         Self::Always,
         Self::Buff {
             combatant: Combatant::DEFAULT,
             ids: Vec::new(),
         },
+        Self::BuffCategory {
+            combatant: Combatant::DEFAULT,
+            categories: BitFlags::EMPTY,
+            stacking: BitFlags::EMPTY,
+            excluded: Vec::new(),
+        },
+        Self::Consumable { items: Vec::new() },
         Self::Ability { ids: Vec::new() },
         Self::SkillbarSlot {
             slot: Slot::DEFAULT,
@@ -143,6 +229,19 @@ impl VariantArray for ProgressSource {
         Self::PrimaryResource,
         Self::SecondaryResource,
         Self::ResourceRate,
+        Self::DayCycle {
+            phases: BitFlags::EMPTY,
+        },
+        Self::Event {
+            name: String::new(),
+        },
+        Self::Timer {
+            name: String::new(),
+        },
+        Self::Variable {
+            name: String::new(),
+            max: 100.0,
+        },
     ];
 }
 
@@ -154,6 +253,14 @@ impl ProgressSource {
         matches!(self, Self::Inherit)
     }
 
+    /// Returns the reference target if the source is a reference.
+    pub fn reference(&self) -> Option<&str> {
+        match self {
+            Self::Reference { target } => Some(target),
+            _ => None,
+        }
+    }
+
     /// Whether the source skips threshold checks.
     pub const fn no_threshold(&self) -> bool {
         matches!(self, Self::Always)
@@ -163,16 +270,23 @@ impl ProgressSource {
     pub const fn update_on(&self) -> Updates {
         match self {
             Self::Inherit => Updates::ALL, // dont know when parent updates
+            Self::Reference { .. } => Updates::ALL, // dont know when target updates
             Self::Always => Updates::EMPTY,
-            Self::Buff { combatant, .. } => match combatant {
-                Combatant::Player => Update::PlayerBuffs.flags(),
-                Combatant::Pet => Updates::EMPTY,
-                Combatant::Target => Update::TargetBuffs.flags(),
-                Combatant::GroupMember1
-                | Combatant::GroupMember2
-                | Combatant::GroupMember3
-                | Combatant::GroupMember4 => Update::GroupBuffs.flags(),
-            },
+            Self::Buff { combatant, .. } | Self::BuffCategory { combatant, .. } => {
+                match combatant {
+                    Combatant::Player => Update::PlayerBuffs.flags(),
+                    Combatant::Pet => Updates::EMPTY,
+                    Combatant::Target => Update::TargetBuffs.flags(),
+                    Combatant::GroupMember1
+                    | Combatant::GroupMember2
+                    | Combatant::GroupMember3
+                    | Combatant::GroupMember4
+                    | Combatant::RepeaterMember => {
+                        make_bitflags!(Update::{GroupIdentity | GroupBuffs})
+                    }
+                }
+            }
+            Self::Consumable { .. } => Update::PlayerBuffs.flags(),
             Self::Ability { .. } | Self::SkillbarSlot { .. } => Update::PlayerSkillbar.flags(),
             Self::Health { combatant }
             | Self::Barrier { combatant }
@@ -183,13 +297,19 @@ impl ProgressSource {
                 Combatant::GroupMember1
                 | Combatant::GroupMember2
                 | Combatant::GroupMember3
-                | Combatant::GroupMember4 => Update::GroupResources.flags(),
+                | Combatant::GroupMember4
+                | Combatant::RepeaterMember => {
+                    make_bitflags!(Update::{GroupIdentity | GroupResources})
+                }
             },
             Self::HealthReduction
             | Self::Endurance
             | Self::PrimaryResource
             | Self::SecondaryResource
             | Self::ResourceRate => Update::PlayerResources.flags(),
+            Self::DayCycle { .. } | Self::Event { .. } => Update::Time.flags(),
+            Self::Timer { .. } => Update::Timers.flags(),
+            Self::Variable { .. } => Update::Variables.flags(),
         }
     }
 
@@ -203,27 +323,51 @@ impl ProgressSource {
     }
 
     /// Resolves the current [`ProgressActive`].
+    ///
+    /// The member index is used for [`Combatant::RepeaterMember`].
     pub fn progress(
         &self,
         ctx: &Context,
         parent: Option<&ProgressActive>,
+        member: Option<usize>,
     ) -> Option<ProgressActive> {
         match *self {
-            Self::Inherit => parent.cloned(),
+            Self::Inherit | Self::Reference { .. } => parent.cloned(),
             Self::Always => Some(ProgressActive::always()),
             Self::Buff { combatant, ref ids } => {
-                let buffs = combatant.buffs(ctx)?;
-                let mut combined = Buff::empty();
-                for id in ids {
-                    if let Some(buff) = buffs.get(id).filter(|buff| buff.runout_time > ctx.now) {
-                        combined.stacks += buff.stacks;
-                        combined.apply_time = combined.apply_time.max(buff.apply_time);
-                        combined.runout_time = combined.runout_time.max(buff.runout_time);
-                    }
-                }
+                let buffs = combatant.buffs(ctx, member)?;
+                let combined = Self::buffs_combined(buffs, ctx.now, ids.iter().copied());
+                let progress_id = ids.first().copied().unwrap_or_default();
+                Some(ProgressActive::from_buff(progress_id, &combined))
+            }
+            Self::Consumable { ref items } => {
+                let buffs = Combatant::Player.buffs(ctx, None)?;
+                let ids = consumable::consumable_buffs(buffs.keys(), items);
+                let combined = Self::buffs_combined(buffs, ctx.now, ids.iter().copied());
                 let progress_id = ids.first().copied().unwrap_or_default();
                 Some(ProgressActive::from_buff(progress_id, &combined))
             }
+            Self::BuffCategory {
+                combatant,
+                categories,
+                stacking,
+                ref excluded,
+            } => {
+                let buffs = combatant.buffs(ctx, member)?;
+                let combined = Self::buff_category_combined(buffs, ctx.now, excluded, |id| {
+                    matches!(
+                        Internal::get_skill_info(id),
+                        Ok(SkillInfo::Buff {
+                            category: buff_category,
+                            stacking: buff_stacking,
+                            visibility,
+                        }) if (categories.is_empty() || categories.contains(buff_category))
+                            && (stacking.is_empty() || stacking.contains(buff_stacking))
+                            && combatant.supports_visibility(visibility)
+                    )
+                });
+                Some(ProgressActive::from_buff(0, &combined))
+            }
             Self::Ability { ref ids } => {
                 let skillbar = ctx.player.skillbar.as_ref().ok()?;
                 ids.iter()
@@ -238,7 +382,7 @@ impl ProgressSource {
                 Some(ProgressActive::from_ability(ability))
             }
             Self::Health { combatant } => {
-                let resources = combatant.resources(ctx)?;
+                let resources = combatant.resources(ctx, member)?;
                 ProgressActive::from_resource(&resources.health, ResourceType::Health)
             }
             Self::HealthReduction => {
@@ -246,11 +390,11 @@ impl ProgressSource {
                 ProgressActive::from_resource(&resources.health_reduction, ResourceType::Health)
             }
             Self::Barrier { combatant } => {
-                let resources = combatant.resources(ctx)?;
+                let resources = combatant.resources(ctx, member)?;
                 ProgressActive::from_resource(&resources.barrier, ResourceType::Barrier)
             }
             Self::Defiance { combatant } => {
-                let defiance = &combatant.resources(ctx)?.defiance;
+                let defiance = &combatant.resources(ctx, member)?.defiance;
                 let current = defiance.percent()?;
                 let resource_type = defiance.resource_type();
                 Some(ProgressActive::percent(current, resource_type))
@@ -271,6 +415,59 @@ impl ProgressSource {
                 let resources = ctx.player.resources.as_ref().ok()?;
                 ProgressActive::from_resource(&resources.rate, ResourceType::Profession)
             }
+            Self::DayCycle { phases } => {
+                let (phase, remaining) = ctx.time.day_cycle();
+                let stacks = u32::from(phases.is_empty() || phases.contains(phase));
+                Some(ProgressActive::from_remaining(
+                    stacks,
+                    phase.duration(),
+                    remaining,
+                    ctx.now,
+                ))
+            }
+            Self::Event { ref name } => {
+                let schedule = ctx.event_schedule(name)?;
+                Some(match schedule.state(ctx.time.timestamp) {
+                    ScheduleState::Active { remaining } => ProgressActive::from_remaining(
+                        1,
+                        schedule.duration_millis(),
+                        remaining,
+                        ctx.now,
+                    ),
+                    ScheduleState::Upcoming { remaining } => ProgressActive::from_remaining(
+                        0,
+                        schedule.wait_millis(),
+                        remaining,
+                        ctx.now,
+                    ),
+                })
+            }
+            Self::Timer { ref name } => {
+                let timer = ctx.timer(name)?;
+                Some(match timer.remaining(ctx.now) {
+                    Some(remaining) => {
+                        ProgressActive::from_remaining(1, timer.duration, remaining, ctx.now)
+                    }
+                    None => ProgressActive::from_remaining(0, timer.duration, 0, ctx.now),
+                })
+            }
+            Self::Variable { ref name, max } => {
+                let variable = ctx.variables.get(name)?;
+                Some(if variable.is_timed() {
+                    ProgressActive::from_remaining(
+                        variable.value.max(0.0).round() as u32,
+                        variable.duration,
+                        variable.remaining(ctx.now),
+                        ctx.now,
+                    )
+                } else {
+                    ProgressActive::Resource {
+                        current: variable.value,
+                        max,
+                        resource: ResourceType::Generic,
+                    }
+                })
+            }
         }
     }
 
@@ -281,12 +478,15 @@ impl ProgressSource {
         let passed = ctx.now % CYCLE;
         let progress = passed as f32 / CYCLE as f32;
         match *self {
-            Self::Inherit => parent.cloned().unwrap_or(ProgressActive::always()),
+            Self::Inherit | Self::Reference { .. } => {
+                parent.cloned().unwrap_or(ProgressActive::always())
+            }
             Self::Always => ProgressActive::always(),
             Self::Buff { ref ids, .. } => {
                 let id = ids.first().copied().unwrap_or(0);
                 ProgressActive::edit_buff(id, progress, ctx.now)
             }
+            Self::Consumable { .. } => ProgressActive::edit_buff(0, progress, ctx.now),
             Self::Ability { ref ids } => {
                 let id = ids.first().copied().unwrap_or(0);
                 ProgressActive::edit_ability(id.into(), progress, ctx.now)
@@ -317,7 +517,53 @@ impl ProgressSource {
             Self::PrimaryResource | Self::SecondaryResource | Self::ResourceRate => {
                 ProgressActive::edit_resource(progress, 30.0, ResourceType::Profession)
             }
+            Self::BuffCategory { .. }
+            | Self::DayCycle { .. }
+            | Self::Event { .. }
+            | Self::Timer { .. } => ProgressActive::edit_buff(0, progress, ctx.now),
+            Self::Variable { ref name, max } => match ctx.variables.get(name) {
+                Some(variable) if variable.is_timed() => {
+                    ProgressActive::edit_buff(0, progress, ctx.now)
+                }
+                _ => ProgressActive::edit_resource(progress, max, ResourceType::Generic),
+            },
+        }
+    }
+
+    /// Merges the present buffs with the given ids.
+    fn buffs_combined(buffs: &BuffMap, now: u32, ids: impl IntoIterator<Item = u32>) -> Buff {
+        let mut combined = Buff::empty();
+        for id in ids {
+            if let Some(buff) = buffs.get(&id).filter(|buff| buff.runout_time > now) {
+                combined.stacks += buff.stacks;
+                combined.apply_time = combined.apply_time.max(buff.apply_time);
+                combined.runout_time = combined.runout_time.max(buff.runout_time);
+            }
         }
+        combined
+    }
+
+    /// Combines the visible buffs passing the match, excluding the given ids.
+    ///
+    /// Stacks are the number of distinct matching buffs.
+    /// Times are taken from the buff running out last.
+    fn buff_category_combined(
+        buffs: &BuffMap,
+        now: u32,
+        excluded: &[u32],
+        mut matches: impl FnMut(u32) -> bool,
+    ) -> Buff {
+        let mut combined = Buff::empty();
+        for (id, buff) in buffs {
+            if buff.runout_time > now && !excluded.contains(id) && matches(*id) {
+                if combined.stacks == 0 || buff.runout_time > combined.runout_time {
+                    combined.apply_time = buff.apply_time;
+                    combined.runout_time = buff.runout_time;
+                }
+                combined.stacks += 1;
+            }
+        }
+        combined
     }
 
     /// Validates the ability id.
@@ -338,8 +584,61 @@ impl ProgressSource {
         });
     }
 
+    /// Validates the event name.
+    fn event_validate(ctx: &Context, name: &str) -> Validation<String> {
+        match ctx.events.iter().find(|event| event.name == name) {
+            Some(event) => match event.parse_schedule() {
+                Ok(_) => Validation::Ok,
+                Err(err) => {
+                    Validation::Error(format!("Event \"{name}\" has invalid schedule: {err}"))
+                }
+            },
+            None if ScheduledEvent::find(name).is_some() => Validation::Ok,
+            None => Validation::Warn(format!("Event \"{name}\" does not exist")),
+        }
+    }
+
+    /// Validates the timer name.
+    fn timer_validate(ctx: &Context, name: &str) -> Validation<String> {
+        match ctx.timer(name) {
+            Some(_) => Validation::Ok,
+            None => Validation::Warn(format!("Timer \"{name}\" does not exist")),
+        }
+    }
+
+    /// Validates the variable name.
+    fn variable_validate(ctx: &Context, name: &str) -> Validation<String> {
+        match ctx.variables.get(name) {
+            Some(variable) => Validation::Confirm(format!(
+                "Variable \"{name}\" is currently {}",
+                variable.value
+            )),
+            None => Validation::Ok,
+        }
+    }
+
+    /// Renders an event selection.
+    fn event_select(ui: &Ui, ctx: &Context, name: &mut String) -> bool {
+        let mut changed = false;
+        if let Some(_token) = ui.begin_combo("Event", name.as_str()) {
+            let custom = ctx.events.iter().map(|event| event.name.as_str());
+            let builtin = ScheduledEvent::BUILTIN.iter().map(|event| event.name);
+            for event in custom.chain(builtin) {
+                let selected = event == name;
+                if Selectable::new(event).selected(selected).build(ui) {
+                    *name = event.into();
+                    changed = true;
+                }
+                if selected {
+                    ui.set_item_default_focus();
+                }
+            }
+        }
+        changed
+    }
+
     /// Renders progress source options.
-    pub fn render_options(&mut self, ui: &Ui) -> bool {
+    pub fn render_options(&mut self, ui: &Ui, ctx: &Context) -> bool {
         let mut changed = false;
 
         if let Some(prev) = enum_combo(ui, "Trigger", self, ComboBoxFlags::HEIGHT_LARGE) {
@@ -349,6 +648,7 @@ impl ProgressSource {
         helper(ui, || {
             ui.text("Source of information");
             ui.text("Effect merges all matches");
+            ui.text("Effect Category counts all matches");
             ui.text("Ability uses first match");
             ui.text("Does not affect group visibility, only passed down for inherit");
         });
@@ -378,6 +678,69 @@ impl ProgressSource {
 
                 changed |= action.perform(ids);
             }
+            Self::BuffCategory {
+                combatant,
+                categories,
+                stacking,
+                excluded,
+            } => {
+                let validation = combatant.validate_buff();
+                changed |= combatant.render_options(ui, validation);
+
+                changed |= enum_combo_bitflags(ui, "Category", categories, ComboBoxFlags::empty());
+                helper(ui, || ui.text("Categories to match, any if empty"));
+
+                changed |= enum_combo_bitflags(ui, "Stacking", stacking, ComboBoxFlags::empty());
+                helper(ui, || ui.text("Stacking types to match, any if empty"));
+
+                let mut action = Action::new();
+                for (i, id) in excluded.iter_mut().enumerate() {
+                    let _id = ui.push_id(i as i32);
+
+                    action.input_with_buttons(ui, i, || {
+                        combatant.validate_buff_id(*id).for_item(ui, || {
+                            changed |= input_skill_id(ui, "##id", id, InputTextFlags::empty());
+                        });
+                    });
+
+                    ui.same_line();
+                    ui.text(format!("Excluded Id {}", i + 1));
+                    Self::id_helper(ui);
+                }
+                if ui.button("Add Exclusion") {
+                    excluded.push(0);
+                }
+                helper(ui, || {
+                    ui.text("Intensity is the number of distinct matching effects");
+                    ui.text("Duration is taken from the effect running out last");
+                });
+
+                changed |= action.perform(excluded);
+            }
+            Self::Consumable { items } => {
+                let mut action = Action::new();
+                for (i, item) in items.iter_mut().enumerate() {
+                    let _id = ui.push_id(i as i32);
+
+                    action.input_with_buttons(ui, i, || {
+                        changed |= input_item_id(ui, "##id", item, InputTextFlags::empty());
+                    });
+
+                    ui.same_line();
+                    ui.text(format!("Item Id {}", i + 1));
+                    Self::id_helper(ui);
+                }
+                if ui.button("Add Consumable") {
+                    items.push(0);
+                }
+                helper(ui, || {
+                    ui.text("Food, utility or augmentation items");
+                    ui.text("Matches effects applied by the items");
+                    ui.text("Use a missing threshold for reminders");
+                });
+
+                changed |= action.perform(items);
+            }
             Self::Ability { ids } => {
                 let mut action = Action::new();
                 for (i, id) in ids.iter_mut().enumerate() {
@@ -410,9 +773,134 @@ impl ProgressSource {
                 let validation = combatant.validate_defiance();
                 changed |= combatant.render_options(ui, validation);
             }
+            Self::DayCycle { phases } => {
+                changed |= enum_combo_bitflags(ui, "Phase", phases, ComboBoxFlags::empty());
+                helper(ui, || {
+                    ui.text("1 stack during the selected phases, any if empty");
+                    ui.text("0 stacks otherwise, use the Present threshold to hide");
+                    ui.text("Duration shows the time left in the current phase");
+                });
+            }
+            Self::Event { name } => {
+                Self::event_validate(ctx, name)
+                    .for_item(ui, || changed |= Self::event_select(ui, ctx, name));
+                helper(ui, || {
+                    ui.text("Present while the event is ongoing");
+                    ui.text("Duration shows the time left or until the next start");
+                    ui.text("Custom events are managed in the settings");
+                });
+            }
+            Self::Timer { name } => {
+                Self::timer_validate(ctx, name).for_item(ui, || {
+                    let timers = ctx.timers.iter().map(|timer| timer.name.as_str());
+                    changed |= name_combo(ui, "Timer", name, timers, false);
+                });
+                helper(ui, || {
+                    ui.text("Present while the timer is running");
+                    ui.text("Started via keybind, Nexus event or another trigger");
+                    ui.text("Timers are managed in the settings");
+                });
+            }
+            Self::Reference { target } => {
+                let validation = if target.is_empty() {
+                    Validation::Error("Reference target is required")
+                } else {
+                    Validation::Ok
+                };
+                validation.for_item(ui, || {
+                    changed |= ui
+                        .input_text("Target", target)
+                        .hint("Key or Group/Element")
+                        .build();
+                });
+                helper(ui, || {
+                    ui.text("Reuses the trigger of another element in the same pack");
+                    ui.text("Target by element key or path of element names");
+                    ui.text("Elements inside repeaters can not be referenced");
+                });
+            }
+            Self::Variable { name, max } => {
+                Self::variable_validate(ctx, name).for_item(ui, || {
+                    changed |= ui.input_text("Variable", name).build();
+                });
+                item_context_menu("varselectctx", || {
+                    for variable in ctx.variables.names() {
+                        if MenuItem::new(variable).build(ui) {
+                            *name = variable.into();
+                            changed = true;
+                        }
+                    }
+                });
+                helper(ui, || {
+                    ui.text("Variables are set by other addons via Nexus events");
+                    ui.text("Right click to select a currently set variable");
+                    ui.text("Present while the variable is set");
+                });
+
+                changed |= input_positive_with_format(
+                    "Max",
+                    max,
+                    1.0,
+                    10.0,
+                    "%.2f",
+                    InputTextFlags::empty(),
+                );
+                helper(ui, || {
+                    ui.text("Maximum value for variables without duration");
+                    ui.text("Variables with duration use their value as intensity");
+                });
+            }
             _ => {}
         }
 
         changed
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buff_category() {
+        let buffs = BuffMap::from([
+            (
+                1,
+                Buff {
+                    stacks: 5,
+                    apply_time: 1000,
+                    runout_time: 5000,
+                },
+            ),
+            (
+                2,
+                Buff {
+                    stacks: 1,
+                    apply_time: 2000,
+                    runout_time: 8000,
+                },
+            ),
+            (
+                3,
+                Buff {
+                    stacks: 1,
+                    apply_time: 500,
+                    runout_time: 1500,
+                },
+            ),
+            (4, Buff::hidden_duration()),
+        ]);
+
+        let combined = ProgressSource::buff_category_combined(&buffs, 3000, &[], |id| id != 4);
+        assert_eq!(combined.stacks, 2);
+        assert_eq!(combined.apply_time, 2000);
+        assert_eq!(combined.runout_time, 8000);
+
+        let combined = ProgressSource::buff_category_combined(&buffs, 3000, &[2], |_| true);
+        assert_eq!(combined.stacks, 2);
+        assert!(combined.is_infinite());
+
+        let combined = ProgressSource::buff_category_combined(&buffs, 3000, &[], |_| false);
+        assert_eq!(combined.stacks, 0);
+    }
+}
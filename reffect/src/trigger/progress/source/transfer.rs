@@ -28,20 +28,27 @@ impl From<ProgressSource> for Transfer {
                 ids,
                 ..Self::default()
             },
-            ProgressSource::Health { combatant }
+            ProgressSource::BuffCategory { combatant, .. }
+            | ProgressSource::Health { combatant }
             | ProgressSource::Barrier { combatant }
             | ProgressSource::Defiance { combatant } => Self {
                 combatant,
                 ..Self::default()
             },
             ProgressSource::Inherit
+            | ProgressSource::Reference { .. }
             | ProgressSource::Always
+            | ProgressSource::Consumable { .. }
             | ProgressSource::SkillbarSlot { .. }
             | ProgressSource::HealthReduction
             | ProgressSource::Endurance
             | ProgressSource::PrimaryResource
             | ProgressSource::SecondaryResource
-            | ProgressSource::ResourceRate => Self::default(),
+            | ProgressSource::ResourceRate
+            | ProgressSource::DayCycle { .. }
+            | ProgressSource::Event { .. }
+            | ProgressSource::Timer { .. }
+            | ProgressSource::Variable { .. } => Self::default(),
         }
     }
 }
@@ -60,17 +67,24 @@ impl Transfer {
                 *ids = self.ids;
             }
             ProgressSource::Ability { ids } => *ids = self.ids,
-            ProgressSource::Health { combatant }
+            ProgressSource::BuffCategory { combatant, .. }
+            | ProgressSource::Health { combatant }
             | ProgressSource::Barrier { combatant }
             | ProgressSource::Defiance { combatant } => *combatant = self.combatant,
             ProgressSource::Inherit
+            | ProgressSource::Reference { .. }
             | ProgressSource::Always
+            | ProgressSource::Consumable { .. }
             | ProgressSource::SkillbarSlot { .. }
             | ProgressSource::HealthReduction
             | ProgressSource::Endurance
             | ProgressSource::PrimaryResource
             | ProgressSource::SecondaryResource
-            | ProgressSource::ResourceRate => {}
+            | ProgressSource::ResourceRate
+            | ProgressSource::DayCycle { .. }
+            | ProgressSource::Event { .. }
+            | ProgressSource::Timer { .. }
+            | ProgressSource::Variable { .. } => {}
         }
     }
 }
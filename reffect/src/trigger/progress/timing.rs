@@ -0,0 +1,331 @@
+use super::ProgressActive;
+use crate::{
+    colors::{Color, Colored},
+    named::Named,
+    render::{enum_combo_bitflags, helper, input_seconds},
+    serde::bitflags,
+};
+use const_default::ConstDefault;
+use enumflags2::{BitFlags, bitflags};
+use nexus::imgui::{ComboBoxFlags, Ui};
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumIter, IntoStaticStr, VariantArray};
+
+/// Timing options for a progress trigger.
+///
+/// All times are in milliseconds.
+#[derive(Debug, Default, ConstDefault, PartialEq, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct ProgressTiming {
+    /// Time the trigger needs to be active before showing.
+    pub delay: u32,
+
+    /// Time to keep showing after the trigger stopped being active.
+    pub linger: u32,
+
+    /// Minimum time to keep showing once shown.
+    pub min_display: u32,
+
+    /// Edges to show on instead of showing while active.
+    #[serde(with = "bitflags")]
+    #[cfg_attr(feature = "schema", schemars(with = "bitflags::Schema<Edge>"))]
+    pub edges: BitFlags<Edge>,
+
+    /// Time to show after an edge.
+    pub edge_duration: u32,
+}
+
+impl ProgressTiming {
+    /// Whether any timing option is set.
+    pub const fn is_set(&self) -> bool {
+        self.delay > 0 || self.linger > 0 || self.min_display > 0 || !self.edges.is_empty()
+    }
+
+    /// Renders the timing options.
+    pub fn render_options(&mut self, ui: &Ui) -> bool {
+        let mut changed = false;
+
+        changed |= input_seconds(ui, "Delay", &mut self.delay);
+        helper(ui, || {
+            ui.text("Time the trigger needs to be active before showing");
+            ui.text("Avoids showing for short flickers");
+        });
+
+        changed |= input_seconds(ui, "Linger", &mut self.linger);
+        helper(ui, || {
+            ui.text("Time to keep showing after the trigger stopped being active");
+            ui.text("Avoids blinking when briefly inactive");
+        });
+
+        changed |= input_seconds(ui, "Min Display", &mut self.min_display);
+        helper(ui, || ui.text("Minimum time to keep showing once shown"));
+
+        changed |= enum_combo_bitflags(ui, "Show on", &mut self.edges, ComboBoxFlags::empty());
+        helper(ui, || {
+            ui.text("Show only for a time after the trigger gains or loses activity");
+            ui.text("Shows while active if empty");
+        });
+
+        if !self.edges.is_empty() {
+            changed |= input_seconds(ui, "Show for", &mut self.edge_duration);
+            helper(ui, || ui.text("Time to show after the trigger changed"));
+        }
+
+        changed
+    }
+}
+
+/// Memoized timing state of a progress trigger.
+#[derive(Debug, Default, ConstDefault, Clone)]
+pub struct TimingState {
+    /// Timestamp since the trigger is active.
+    active_since: Option<u32>,
+
+    /// Timestamp since the trigger is shown.
+    shown_since: Option<u32>,
+
+    /// Timestamp since the shown trigger is inactive.
+    inactive_since: Option<u32>,
+
+    /// Last shown active progress.
+    last: Option<ProgressActive>,
+
+    /// Last edge and its timestamp.
+    edge: Option<(Edge, u32)>,
+
+    /// Timestamp until the last edge is shown.
+    edge_until: Option<u32>,
+}
+
+impl TimingState {
+    /// Resets the timing state.
+    pub fn reset(&mut self) {
+        *self = Self::DEFAULT;
+    }
+
+    /// Whether the state is waiting for a delay, linger or minimum display to pass.
+    pub fn is_pending(&self) -> bool {
+        let delaying = self.active_since.is_some() && self.shown_since.is_none();
+        delaying || self.inactive_since.is_some() || self.edge_until.is_some()
+    }
+
+    /// Returns the last edge and its timestamp.
+    pub fn last_edge(&self) -> Option<(Edge, u32)> {
+        self.edge
+    }
+
+    /// Applies the timing to the resolved active progress, returning the progress to show.
+    pub fn apply(
+        &mut self,
+        timing: &ProgressTiming,
+        active: Option<ProgressActive>,
+        now: u32,
+    ) -> Option<ProgressActive> {
+        let was_shown = self.shown_since.is_some();
+        let shown = self.apply_level(timing, active, now);
+
+        let is_shown = self.shown_since.is_some();
+        if was_shown != is_shown {
+            let edge = if is_shown { Edge::Gain } else { Edge::Loss };
+            self.edge = Some((edge, now));
+            self.edge_until = timing
+                .edges
+                .contains(edge)
+                .then(|| now.saturating_add(timing.edge_duration));
+        }
+
+        if timing.edges.is_empty() {
+            shown
+        } else {
+            match self.edge_until {
+                Some(until) if now < until => shown.or_else(|| self.last.clone()),
+                _ => {
+                    self.edge_until = None;
+                    None
+                }
+            }
+        }
+    }
+
+    /// Applies delay, linger and minimum display.
+    fn apply_level(
+        &mut self,
+        timing: &ProgressTiming,
+        active: Option<ProgressActive>,
+        now: u32,
+    ) -> Option<ProgressActive> {
+        match active {
+            Some(active) => {
+                self.inactive_since = None;
+                let active_since = *self.active_since.get_or_insert(now);
+                if self.shown_since.is_some() || now.saturating_sub(active_since) >= timing.delay {
+                    self.shown_since.get_or_insert(now);
+                    self.last = Some(active.clone());
+                    Some(active)
+                } else {
+                    None
+                }
+            }
+            None => {
+                self.active_since = None;
+                if let Some(shown_since) = self.shown_since {
+                    let inactive_since = *self.inactive_since.get_or_insert(now);
+                    let until = (inactive_since.saturating_add(timing.linger))
+                        .max(shown_since.saturating_add(timing.min_display));
+                    if now < until {
+                        return self.last.clone();
+                    }
+                }
+                // keep last progress for loss edges
+                self.shown_since = None;
+                self.inactive_since = None;
+                None
+            }
+        }
+    }
+}
+
+/// Change in whether a trigger is active.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    AsRefStr,
+    IntoStaticStr,
+    Display,
+    EnumIter,
+    VariantArray,
+    Serialize,
+    Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[bitflags]
+#[repr(u8)]
+pub enum Edge {
+    /// Trigger became active.
+    Gain = 1 << 0,
+
+    /// Trigger stopped being active.
+    Loss = 1 << 1,
+}
+
+impl Named for Edge {
+    fn name(&self) -> &'static str {
+        self.into()
+    }
+
+    fn short_name(&self) -> &'static str {
+        self.name()
+    }
+}
+
+impl Colored for Edge {
+    fn colored(&self) -> Option<Color> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn active() -> Option<ProgressActive> {
+        Some(ProgressActive::always())
+    }
+
+    #[test]
+    fn no_timing() {
+        let timing = ProgressTiming::default();
+        let mut state = TimingState::default();
+
+        assert_eq!(state.apply(&timing, active(), 0), active());
+        assert_eq!(state.apply(&timing, None, 100), None);
+        assert!(!state.is_pending());
+    }
+
+    #[test]
+    fn delay() {
+        let timing = ProgressTiming {
+            delay: 500,
+            ..ProgressTiming::default()
+        };
+        let mut state = TimingState::default();
+
+        assert_eq!(state.apply(&timing, active(), 1000), None);
+        assert!(state.is_pending());
+        assert_eq!(state.apply(&timing, active(), 1200), None);
+        assert_eq!(state.apply(&timing, active(), 1500), active());
+        assert!(!state.is_pending());
+
+        // flicker resets the delay
+        assert_eq!(state.apply(&timing, None, 1600), None);
+        assert_eq!(state.apply(&timing, active(), 1700), None);
+        assert_eq!(state.apply(&timing, None, 1800), None);
+        assert!(!state.is_pending());
+    }
+
+    #[test]
+    fn linger_min_display() {
+        let timing = ProgressTiming {
+            linger: 300,
+            min_display: 1000,
+            ..ProgressTiming::default()
+        };
+        let mut state = TimingState::default();
+
+        assert_eq!(state.apply(&timing, active(), 0), active());
+        assert_eq!(state.apply(&timing, None, 100), active());
+        assert!(state.is_pending());
+        assert_eq!(state.apply(&timing, None, 900), active());
+        assert_eq!(state.apply(&timing, None, 1000), None);
+        assert!(!state.is_pending());
+
+        assert_eq!(state.apply(&timing, active(), 2000), active());
+        assert_eq!(state.apply(&timing, None, 3000), active());
+        assert_eq!(state.apply(&timing, active(), 3100), active());
+        assert_eq!(state.apply(&timing, None, 3200), active());
+        assert_eq!(state.apply(&timing, None, 3500), None);
+    }
+
+    #[test]
+    fn edges() {
+        let timing = ProgressTiming {
+            edges: Edge::Gain.into(),
+            edge_duration: 1000,
+            ..ProgressTiming::default()
+        };
+        let mut state = TimingState::default();
+
+        assert_eq!(state.apply(&timing, None, 0), None);
+        assert_eq!(state.last_edge(), None);
+        assert_eq!(state.apply(&timing, active(), 1000), active());
+        assert_eq!(state.last_edge(), Some((Edge::Gain, 1000)));
+        assert!(state.is_pending());
+        assert_eq!(state.apply(&timing, active(), 1500), active());
+        assert_eq!(state.apply(&timing, active(), 2000), None);
+        assert!(!state.is_pending());
+        assert_eq!(state.apply(&timing, None, 2500), None);
+        assert_eq!(state.last_edge(), Some((Edge::Loss, 2500)));
+        assert!(!state.is_pending());
+
+        let timing = ProgressTiming {
+            edges: Edge::Loss.into(),
+            edge_duration: 1000,
+            ..ProgressTiming::default()
+        };
+        let mut state = TimingState::default();
+
+        assert_eq!(state.apply(&timing, active(), 0), None);
+        assert_eq!(state.apply(&timing, None, 500), active());
+        assert!(state.is_pending());
+        assert_eq!(state.apply(&timing, None, 1000), active());
+        assert_eq!(state.apply(&timing, None, 1500), None);
+        assert!(!state.is_pending());
+    }
+}
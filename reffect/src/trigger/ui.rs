@@ -0,0 +1,97 @@
+use crate::{
+    context::{Context, UiFlag},
+    render::{enum_combo_bitflags, helper},
+    serde::bitflags,
+};
+use const_default::ConstDefault;
+use enumflags2::BitFlags;
+use nexus::imgui::{ComboBoxFlags, Ui};
+use serde::{Deserialize, Serialize};
+
+/// Game UI state trigger.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct UiTrigger {
+    /// UI states required to be set.
+    #[serde(with = "bitflags")]
+    #[cfg_attr(feature = "schema", schemars(with = "bitflags::Schema<UiFlag>"))]
+    pub required: BitFlags<UiFlag>,
+
+    /// UI states required to be unset.
+    #[serde(with = "bitflags")]
+    #[cfg_attr(feature = "schema", schemars(with = "bitflags::Schema<UiFlag>"))]
+    pub excluded: BitFlags<UiFlag>,
+}
+
+impl ConstDefault for UiTrigger {
+    const DEFAULT: Self = Self {
+        required: BitFlags::EMPTY,
+        excluded: BitFlags::EMPTY,
+    };
+}
+
+impl Default for UiTrigger {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl UiTrigger {
+    /// Checks whether the UI trigger is active.
+    pub fn is_active(&self, ctx: &Context) -> bool {
+        let flags = ctx.ui.flags();
+        flags.contains(self.required) && !flags.intersects(self.excluded)
+    }
+
+    /// Renders UI trigger options.
+    pub fn render_options(&mut self, ui: &Ui) -> bool {
+        let _id = ui.push_id("ui");
+        let mut changed = false;
+
+        changed |= enum_combo_bitflags(
+            ui,
+            "UI Required",
+            &mut self.required,
+            ComboBoxFlags::empty(),
+        );
+        changed |= enum_combo_bitflags(
+            ui,
+            "UI Excluded",
+            &mut self.excluded,
+            ComboBoxFlags::empty(),
+        );
+        helper(ui, || {
+            ui.text("Game UI states required to be set or unset");
+            ui.text("For example exclude Text Input to hide while typing in chat");
+        });
+
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_excluded() {
+        let mut ctx = Context::disabled();
+        let trigger = UiTrigger {
+            required: UiFlag::GameFocus.into(),
+            excluded: UiFlag::TextInput | UiFlag::Competitive,
+        };
+        assert!(UiTrigger::DEFAULT.is_active(&ctx));
+        assert!(!trigger.is_active(&ctx));
+
+        ctx.ui.game_focus = true;
+        assert!(trigger.is_active(&ctx));
+
+        ctx.ui.text_input = true;
+        assert!(!trigger.is_active(&ctx));
+
+        ctx.ui.text_input = false;
+        ctx.ui.competitive = true;
+        assert!(!trigger.is_active(&ctx));
+    }
+}
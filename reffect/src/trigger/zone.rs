@@ -0,0 +1,286 @@
+use crate::{
+    action::Action,
+    context::Context,
+    enums::check_variant_array,
+    render::{enum_combo, helper, input_float_with_format, input_u32},
+};
+use const_default::ConstDefault;
+use nexus::imgui::{ComboBoxFlags, InputTextFlags, Ui};
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, EnumCount, EnumIter, IntoStaticStr, VariantArray};
+
+/// Zone trigger.
+///
+/// Matches when the player position is inside any of the zones.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(default)]
+pub struct ZoneTrigger {
+    /// Map id the zones are located on, `0` for any map.
+    pub map_id: u32,
+
+    /// Zones in map units.
+    pub zones: Vec<Zone>,
+}
+
+impl ConstDefault for ZoneTrigger {
+    const DEFAULT: Self = Self {
+        map_id: 0,
+        zones: Vec::new(),
+    };
+}
+
+impl Default for ZoneTrigger {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl ZoneTrigger {
+    /// Checks whether the zone trigger is active.
+    pub fn is_active(&self, ctx: &Context) -> bool {
+        self.zones.is_empty()
+            || ((self.map_id == 0 || ctx.map.is_on_map(self.map_id))
+                && self
+                    .zones
+                    .iter()
+                    .any(|zone| zone.contains(ctx.map.position)))
+    }
+
+    /// Renders zone trigger options.
+    pub fn render_options(&mut self, ui: &Ui, ctx: &Context) -> bool {
+        let _id = ui.push_id("zone");
+        let mut changed = false;
+
+        let mut action = Action::new();
+        for (i, zone) in self.zones.iter_mut().enumerate() {
+            let _id = ui.push_id(i as i32);
+            changed |= action.input_with_buttons(ui, i, || {
+                enum_combo(ui, "##shape", zone, ComboBoxFlags::empty()).is_some()
+            });
+
+            ui.same_line();
+            ui.text(format!("Zone {}", i + 1));
+
+            changed |= zone.render_options(ui, ctx);
+        }
+        if ui.button("Add Zone") {
+            self.zones.push(Zone::DEFAULT);
+            changed = true;
+        }
+        helper(ui, || {
+            ui.text("Only show while the player is inside any of the zones");
+            ui.text("Use the record buttons to insert the current position");
+        });
+
+        changed |= action.perform(&mut self.zones);
+
+        if !self.zones.is_empty() {
+            changed |= input_u32(ui, "Zone Map Id", &mut self.map_id, 0, 0);
+            ui.same_line();
+            if ui.button("Current##map") {
+                self.map_id = ctx.map.id;
+                changed = true;
+            }
+            helper(ui, || {
+                ui.text("Map the zones are located on, 0 for any map")
+            });
+
+            let [x, y, z] = ctx.map.position;
+            ui.text_disabled(format!("Current position: {x:.0} {y:.0} {z:.0}"));
+        }
+
+        changed
+    }
+}
+
+/// Zone shape in map units.
+///
+/// The `y` axis points up.
+#[derive(
+    Debug, Clone, PartialEq, AsRefStr, IntoStaticStr, EnumIter, EnumCount, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Zone {
+    /// Axis-aligned box spanned by two corners.
+    Box { min: [f32; 3], max: [f32; 3] },
+
+    /// Sphere around a center.
+    Sphere { center: [f32; 3], radius: f32 },
+
+    /// Horizontal polygon with unlimited height.
+    Polygon { points: Vec<[f32; 2]> },
+}
+
+impl VariantArray for Zone {
+    const VARIANTS: &'static [Self] = &[
+        Self::Box {
+            min: [0.0; 3],
+            max: [0.0; 3],
+        },
+        Self::Sphere {
+            center: [0.0; 3],
+            radius: 100.0,
+        },
+        Self::Polygon { points: Vec::new() },
+    ];
+}
+
+const _: () = check_variant_array::<Zone>();
+
+impl ConstDefault for Zone {
+    const DEFAULT: Self = Self::Sphere {
+        center: [0.0; 3],
+        radius: 100.0,
+    };
+}
+
+impl Default for Zone {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Zone {
+    /// Checks whether the position is inside the zone.
+    pub fn contains(&self, position: [f32; 3]) -> bool {
+        match self {
+            Self::Box { min, max } => (0..3).all(|i| {
+                let (low, high) = (min[i].min(max[i]), min[i].max(max[i]));
+                (low..=high).contains(&position[i])
+            }),
+            Self::Sphere { center, radius } => {
+                let dist_sq: f32 = (0..3).map(|i| (position[i] - center[i]).powi(2)).sum();
+                dist_sq <= radius * radius
+            }
+            Self::Polygon { points } => {
+                let [x, _, z] = position;
+                Self::polygon_contains(points, [x, z])
+            }
+        }
+    }
+
+    /// Checks whether the horizontal point is inside the polygon.
+    ///
+    /// Uses the even-odd rule.
+    fn polygon_contains(points: &[[f32; 2]], [x, y]: [f32; 2]) -> bool {
+        let mut inside = false;
+        let mut prev = points.last();
+        for point in points {
+            let [x1, y1] = *point;
+            if let Some(&[x2, y2]) = prev
+                && (y1 > y) != (y2 > y)
+                && x < (x2 - x1) * (y - y1) / (y2 - y1) + x1
+            {
+                inside = !inside;
+            }
+            prev = Some(point);
+        }
+        inside
+    }
+
+    /// Renders zone options.
+    pub fn render_options(&mut self, ui: &Ui, ctx: &Context) -> bool {
+        let position = ctx.map.position;
+        let mut changed = false;
+
+        match self {
+            Self::Box { min, max } => {
+                changed |= Self::input_position(ui, "Min", min, position);
+                changed |= Self::input_position(ui, "Max", max, position);
+            }
+            Self::Sphere { center, radius } => {
+                changed |= Self::input_position(ui, "Center", center, position);
+                if input_float_with_format(
+                    "Radius",
+                    radius,
+                    10.0,
+                    100.0,
+                    "%.0f",
+                    InputTextFlags::empty(),
+                ) {
+                    *radius = radius.max(0.0);
+                    changed = true;
+                }
+            }
+            Self::Polygon { points } => {
+                let mut action = Action::new();
+                for (i, point) in points.iter_mut().enumerate() {
+                    let _id = ui.push_id(i as i32);
+                    changed |= action
+                        .input_with_buttons(ui, i, || ui.input_float2("##point", point).build());
+
+                    ui.same_line();
+                    ui.text(format!("Point {}", i + 1));
+                }
+                if ui.button("Add Point") {
+                    points.push([0.0; 2]);
+                    changed = true;
+                }
+                ui.same_line();
+                if ui.button("Record Point") {
+                    points.push(ctx.map.position_2d());
+                    changed = true;
+                }
+                helper(ui, || ui.text("Points are horizontal x & z coordinates"));
+
+                changed |= action.perform(points);
+            }
+        }
+
+        changed
+    }
+
+    /// Renders a position input with a button to record the current position.
+    fn input_position(ui: &Ui, label: &str, value: &mut [f32; 3], current: [f32; 3]) -> bool {
+        let mut changed = ui.input_float3(label, value).build();
+        ui.same_line();
+        if ui.button(format!("Record##{label}")) {
+            *value = current;
+            changed = true;
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shapes() {
+        let cube = Zone::Box {
+            min: [10.0, 0.0, 10.0],
+            max: [-10.0, 20.0, -10.0],
+        };
+        assert!(cube.contains([0.0, 10.0, 0.0]));
+        assert!(!cube.contains([0.0, 30.0, 0.0]));
+
+        let sphere = Zone::Sphere {
+            center: [100.0, 0.0, 100.0],
+            radius: 10.0,
+        };
+        assert!(sphere.contains([105.0, 5.0, 100.0]));
+        assert!(!sphere.contains([110.0, 5.0, 100.0]));
+    }
+
+    #[test]
+    fn polygon() {
+        // L-shaped polygon
+        let zone = Zone::Polygon {
+            points: vec![
+                [0.0, 0.0],
+                [20.0, 0.0],
+                [20.0, 10.0],
+                [10.0, 10.0],
+                [10.0, 20.0],
+                [0.0, 20.0],
+            ],
+        };
+        assert!(zone.contains([5.0, 1000.0, 15.0]));
+        assert!(zone.contains([15.0, -1000.0, 5.0]));
+        assert!(!zone.contains([15.0, 0.0, 15.0]));
+        assert!(!zone.contains([-5.0, 0.0, 5.0]));
+        assert!(!Zone::Polygon { points: Vec::new() }.contains([0.0; 3]));
+    }
+}
@@ -53,7 +53,8 @@ fn update() {
     ctx.now = 5_000;
     ctx.map = MapInfo {
         id: 50,
-        category: MapCategory::PvE,
+        category: MapCategory::PvE.into(),
+        ..MapInfo::empty()
     };
     ctx.updates = Update::Map.into();
     Updater::update(&ctx, &mut packs);
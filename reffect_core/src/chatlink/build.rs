@@ -0,0 +1,364 @@
+use super::{LinkType, decode_bytes, encode_bytes};
+use crate::context::{ProfSelection, Profession, Specialization};
+use enumflags2::BitFlags;
+
+/// Build template from a chat link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildTemplate {
+    /// Build profession.
+    pub prof: Profession,
+
+    /// Selected specialization lines.
+    pub lines: [TemplateLine; 3],
+
+    /// Terrestrial skill palette ids for heal, 3 utilities and elite.
+    pub skills: [u16; 5],
+
+    /// Aquatic skill palette ids for heal, 3 utilities and elite.
+    pub aquatic_skills: [u16; 5],
+
+    /// Profession-specific selections.
+    pub prof_data: ProfTemplate,
+
+    /// Selected weapon ids.
+    pub weapons: Vec<u16>,
+
+    /// Selected skill override ids.
+    pub skill_overrides: Vec<u32>,
+}
+
+impl BuildTemplate {
+    /// Size of the profession-specific data in bytes.
+    const PROF_DATA_SIZE: usize = 16;
+
+    /// Returns the ids of the selected specialization lines.
+    pub fn spec_lines(&self) -> impl Iterator<Item = u32> + '_ {
+        self.lines.iter().map(|line| line.id).filter(|id| *id != 0)
+    }
+
+    /// Returns the elite specialization or the core profession specialization.
+    pub fn specialization(&self) -> Specialization {
+        self.lines
+            .iter()
+            .find_map(|line| {
+                Specialization::try_from(None, line.id)
+                    .filter(|spec| spec.profession() == self.prof)
+            })
+            .unwrap_or_else(|| self.prof.into())
+    }
+
+    /// Returns the terrestrial profession selections.
+    pub fn prof_selections(&self) -> BitFlags<ProfSelection> {
+        match self.prof_data {
+            ProfTemplate::Revenant { legends, .. } => {
+                legends.into_iter().filter_map(legend_selection).collect()
+            }
+            _ => BitFlags::empty(),
+        }
+    }
+
+    /// Returns the terrestrial pet ids.
+    pub fn pets(&self) -> impl Iterator<Item = u32> {
+        let pets = match self.prof_data {
+            ProfTemplate::Ranger { pets, .. } => pets,
+            _ => [0; 2],
+        };
+        pets.into_iter().filter(|id| *id != 0).map(Into::into)
+    }
+
+    /// Parses a build template from chat link bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut reader = Reader(bytes);
+        if reader.u8()? != LinkType::Build as u8 {
+            return None;
+        }
+        let prof = Profession::try_from(reader.u8()?).ok()?;
+
+        let mut lines = [TemplateLine::default(); 3];
+        for line in &mut lines {
+            line.id = reader.u8()?.into();
+            let traits = reader.u8()?;
+            line.traits = [traits & 0b11, (traits >> 2) & 0b11, (traits >> 4) & 0b11];
+        }
+
+        let mut skills = [0; 5];
+        let mut aquatic_skills = [0; 5];
+        for (skill, aquatic) in skills.iter_mut().zip(&mut aquatic_skills) {
+            *skill = reader.u16()?;
+            *aquatic = reader.u16()?;
+        }
+
+        let mut data = Reader(reader.take(Self::PROF_DATA_SIZE)?);
+        let prof_data = match prof {
+            Profession::Ranger => ProfTemplate::Ranger {
+                pets: [data.u8()?, data.u8()?],
+                aquatic_pets: [data.u8()?, data.u8()?],
+            },
+            Profession::Revenant => ProfTemplate::Revenant {
+                legends: [data.u8()?, data.u8()?],
+                aquatic_legends: [data.u8()?, data.u8()?],
+                inactive_skills: [data.u16()?, data.u16()?, data.u16()?],
+                inactive_aquatic_skills: [data.u16()?, data.u16()?, data.u16()?],
+            },
+            _ => ProfTemplate::None,
+        };
+
+        // weapons & skill overrides are missing in older links
+        let mut weapons = Vec::new();
+        let mut skill_overrides = Vec::new();
+        if !reader.is_empty() {
+            for _ in 0..reader.u8()? {
+                weapons.push(reader.u16()?);
+            }
+            for _ in 0..reader.u8()? {
+                skill_overrides.push(reader.u32()?);
+            }
+        }
+
+        Some(Self {
+            prof,
+            lines,
+            skills,
+            aquatic_skills,
+            prof_data,
+            weapons,
+            skill_overrides,
+        })
+    }
+
+    /// Converts the build template to chat link bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![LinkType::Build as u8, self.prof.id()];
+
+        for line in &self.lines {
+            let [adept, master, grandmaster] = line.traits.map(|choice| choice & 0b11);
+            bytes.push(line.id as u8);
+            bytes.push(adept | (master << 2) | (grandmaster << 4));
+        }
+
+        for (skill, aquatic) in self.skills.iter().zip(&self.aquatic_skills) {
+            bytes.extend(skill.to_le_bytes());
+            bytes.extend(aquatic.to_le_bytes());
+        }
+
+        let data_start = bytes.len();
+        match self.prof_data {
+            ProfTemplate::None => {}
+            ProfTemplate::Ranger { pets, aquatic_pets } => {
+                bytes.extend(pets);
+                bytes.extend(aquatic_pets);
+            }
+            ProfTemplate::Revenant {
+                legends,
+                aquatic_legends,
+                inactive_skills,
+                inactive_aquatic_skills,
+            } => {
+                bytes.extend(legends);
+                bytes.extend(aquatic_legends);
+                for id in inactive_skills.iter().chain(&inactive_aquatic_skills) {
+                    bytes.extend(id.to_le_bytes());
+                }
+            }
+        }
+        bytes.resize(data_start + Self::PROF_DATA_SIZE, 0);
+
+        bytes.push(self.weapons.len() as u8);
+        for id in &self.weapons {
+            bytes.extend(id.to_le_bytes());
+        }
+        bytes.push(self.skill_overrides.len() as u8);
+        for id in &self.skill_overrides {
+            bytes.extend(id.to_le_bytes());
+        }
+
+        bytes
+    }
+}
+
+/// Specialization line in a build template.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TemplateLine {
+    /// Specialization id.
+    pub id: u32,
+
+    /// Trait choices for adept, master and grandmaster tier.
+    ///
+    /// 0 is none, 1 is top, 2 is middle and 3 is bottom.
+    pub traits: [u8; 3],
+}
+
+impl TemplateLine {
+    /// Returns the selected trait ids given the major traits of the specialization line.
+    ///
+    /// Major traits are ordered by tier from adept to grandmaster, each from top to bottom.
+    pub fn selected_traits(&self, major_traits: [u32; 9]) -> impl Iterator<Item = u32> {
+        self.traits
+            .into_iter()
+            .enumerate()
+            .filter(|(_, choice)| (1..=3).contains(choice))
+            .map(move |(tier, choice)| major_traits[3 * tier + choice as usize - 1])
+    }
+}
+
+/// Profession-specific data in a build template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProfTemplate {
+    None,
+
+    /// Ranger pet ids.
+    Ranger {
+        pets: [u8; 2],
+        aquatic_pets: [u8; 2],
+    },
+
+    /// Revenant legend codes and inactive legend skill palette ids.
+    Revenant {
+        legends: [u8; 2],
+        aquatic_legends: [u8; 2],
+        inactive_skills: [u16; 3],
+        inactive_aquatic_skills: [u16; 3],
+    },
+}
+
+/// Converts a build template legend code to a profession selection.
+fn legend_selection(code: u8) -> Option<ProfSelection> {
+    match code {
+        1 => Some(ProfSelection::LegendDragon),
+        2 => Some(ProfSelection::LegendAssassin),
+        3 => Some(ProfSelection::LegendDwarf),
+        4 => Some(ProfSelection::LegendDemon),
+        5 => Some(ProfSelection::LegendRenegade),
+        6 => Some(ProfSelection::LegendCentaur),
+        7 => Some(ProfSelection::LegendAlliance),
+        8 => Some(ProfSelection::LegendEntity),
+        _ => None,
+    }
+}
+
+/// Helper to read little endian values.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let (bytes, rest) = self.0.split_at_checked(len)?;
+        self.0 = rest;
+        Some(bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.take(2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+/// Decodes a build template from a chat link.
+pub fn decode_build(code: &str) -> Option<BuildTemplate> {
+    BuildTemplate::from_bytes(&decode_bytes(code)?)
+}
+
+/// Encodes a build template to a chat link.
+pub fn encode_build(template: &BuildTemplate) -> String {
+    encode_bytes(&template.to_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds() {
+        assert_eq!(
+            decode_build(ELEMENTALIST),
+            Some(BuildTemplate {
+                prof: Profession::Elementalist,
+                lines: [
+                    TemplateLine {
+                        id: 31,
+                        traits: [1, 2, 3]
+                    },
+                    TemplateLine {
+                        id: 41,
+                        traits: [2, 2, 2]
+                    },
+                    TemplateLine {
+                        id: 56,
+                        traits: [3, 1, 2]
+                    },
+                ],
+                skills: [116, 5503, 5506, 5511, 5516],
+                aquatic_skills: [116, 5503, 5506, 5511, 5516],
+                prof_data: ProfTemplate::None,
+                weapons: vec![],
+                skill_overrides: vec![],
+            })
+        );
+
+        let ranger = decode_build(RANGER).expect("failed to decode ranger");
+        assert_eq!(ranger.specialization(), Specialization::Soulbeast);
+        assert_eq!(ranger.spec_lines().collect::<Vec<_>>(), [8, 30, 55]);
+        assert_eq!(ranger.pets().collect::<Vec<_>>(), [59, 46]);
+        assert_eq!(ranger.weapons, [5, 35]);
+        assert_eq!(ranger.skill_overrides, [71936]);
+
+        let revenant = decode_build(REVENANT).expect("failed to decode revenant");
+        assert_eq!(revenant.specialization(), Specialization::Revenant);
+        assert_eq!(
+            revenant.prof_selections(),
+            ProfSelection::LegendDwarf | ProfSelection::LegendCentaur
+        );
+        assert_eq!(
+            revenant.prof_data,
+            ProfTemplate::Revenant {
+                legends: [3, 6],
+                aquatic_legends: [3, 6],
+                inactive_skills: [4614, 4651, 4564],
+                inactive_aquatic_skills: [4614, 4651, 4564],
+            }
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        for code in [RANGER, REVENANT] {
+            assert_eq!(encode_build(&decode_build(code).unwrap()), code);
+        }
+
+        // older links gain empty weapons & overrides
+        let template = decode_build(ELEMENTALIST).unwrap();
+        let encoded = encode_build(&template);
+        assert_ne!(encoded, ELEMENTALIST);
+        assert_eq!(decode_build(&encoded), Some(template));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(decode_build("[&DQYfOSkqOCc="), None); // broken
+        assert_eq!(decode_build("[&DQYfOSkqOCc=]"), None); // truncated
+        assert_eq!(decode_build("[&BuQCAAA=]"), None); // skill
+        assert_eq!(decode_build("[&AgH1WQAA]"), None); // item
+    }
+
+    // weaver without weapons & overrides
+    const ELEMENTALIST: &str = "[&DQYfOSkqOCd0AHQAfxV/FYIVghWHFYcVjBWMFQAAAAAAAAAAAAAAAAAAAAA=]";
+
+    // soulbeast with weapons & overrides
+    const RANGER: &str =
+        "[&DQQIGR4fNxXJMMkwzDDMMM4wzjDSMNIw0TDRMDsuOy4AAAAAAAAAAAAAAAACBQAjAAEAGQEA]";
+
+    // core revenant
+    const REVENANT: &str = "[&DQkPOQMWDBXcEdwRBhIGEisSKxLUEdQRyhHKEQMGAwYGEisS1BEGEisS1BEAAA==]";
+}
@@ -1,3 +1,7 @@
+mod build;
+
+pub use self::build::*;
+
 use base64::prelude::*;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -57,6 +61,43 @@ pub fn decode_trait(code: &str) -> Option<u32> {
     decode_simple_id(LinkType::Trait, code)
 }
 
+/// Decodes a map point of interest id from a chat link.
+pub fn decode_map(code: &str) -> Option<u32> {
+    decode_simple_id(LinkType::Map, code)
+}
+
+/// Encodes bytes to a chat link.
+pub fn encode_bytes(bytes: &[u8]) -> String {
+    format!("[&{}]", BASE64_STANDARD.encode(bytes))
+}
+
+/// Encodes an id as unsigned 24-bit int to a chat link.
+pub fn encode_simple_id(link_type: LinkType, id: u32) -> String {
+    let [id0, id1, id2, _] = id.to_le_bytes();
+    encode_bytes(&[link_type as u8, id0, id1, id2, 0])
+}
+
+/// Encodes an item id to a chat link.
+pub fn encode_item(id: u32) -> String {
+    let [id0, id1, id2, _] = id.to_le_bytes();
+    encode_bytes(&[LinkType::Item as u8, 1, id0, id1, id2, 0])
+}
+
+/// Encodes a skill id to a chat link.
+pub fn encode_skill(id: u32) -> String {
+    encode_simple_id(LinkType::Skill, id)
+}
+
+/// Encodes a trait id to a chat link.
+pub fn encode_trait(id: u32) -> String {
+    encode_simple_id(LinkType::Trait, id)
+}
+
+/// Encodes a map point of interest id to a chat link.
+pub fn encode_map(id: u32) -> String {
+    encode_simple_id(LinkType::Map, id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -96,4 +137,33 @@ mod tests {
         assert_eq!(decode_trait("[&AgH1WQAA]"), None); // item
         assert_eq!(decode_trait("[&BDgAAAA=]"), None); // poi
     }
+
+    #[test]
+    fn maps() {
+        assert_eq!(decode_map("[&BDgAAAA=]"), Some(56)); // poi
+
+        assert_eq!(decode_map("[&BDgAAAA="), None); // broken
+        assert_eq!(decode_map("[&BuQCAAA=]"), None); // skill
+        assert_eq!(decode_map("[&B/IDAAA=]"), None); // trait
+        assert_eq!(decode_map("[&AgH1WQAA]"), None); // item
+    }
+
+    #[test]
+    fn encode() {
+        assert_eq!(encode_item(23040), "[&AgEAWgAA]");
+        assert_eq!(encode_skill(740), "[&BuQCAAA=]");
+        assert_eq!(encode_skill(71936), "[&BgAZAQA=]");
+        assert_eq!(encode_trait(1010), "[&B/IDAAA=]");
+        assert_eq!(encode_map(56), "[&BDgAAAA=]");
+    }
+
+    #[test]
+    fn roundtrip() {
+        for id in [0, 1, 740, 23029, 71936, 0xFFFFFF] {
+            assert_eq!(decode_item(&encode_item(id)), Some(id));
+            assert_eq!(decode_skill(&encode_skill(id)), Some(id));
+            assert_eq!(decode_trait(&encode_trait(id)), Some(id));
+            assert_eq!(decode_map(&encode_map(id)), Some(id));
+        }
+    }
 }
@@ -2,6 +2,7 @@ mod settings;
 
 use crate::{context::ui::UiInfo, id::Id};
 use nexus::imgui::Ui;
+use std::mem;
 
 pub use self::settings::*;
 
@@ -21,6 +22,9 @@ pub struct EditState {
     /// Selected element parents.
     // TODO: keep parents sorted?
     parents: Vec<Id>,
+
+    /// Whether the element tree changed since the last check.
+    tree_changed: bool,
 }
 
 impl EditState {
@@ -31,6 +35,7 @@ impl EditState {
             allowed: true,
             selected: Id::NIL,
             parents: Vec::new(),
+            tree_changed: false,
         }
     }
 
@@ -111,6 +116,18 @@ impl EditState {
         }
     }
 
+    /// Marks the element tree as changed.
+    #[inline]
+    pub fn set_tree_changed(&mut self) {
+        self.tree_changed = true;
+    }
+
+    /// Takes whether the element tree changed since the last check.
+    #[inline]
+    pub fn take_tree_changed(&mut self) -> bool {
+        mem::take(&mut self.tree_changed)
+    }
+
     #[inline]
     pub fn update_allowed(&mut self, ui: &UiInfo) {
         self.allowed = self.settings.during_combat || !ui.combat;
@@ -9,6 +9,9 @@ pub struct GroupMember {
     /// Group member account name.
     pub account: Option<String>,
 
+    /// Squad subgroup of the group member.
+    pub subgroup: Option<u8>,
+
     /// Generic combatant resources.
     pub resources: Result<CombatantResources, Error>,
 
@@ -21,10 +24,25 @@ impl GroupMember {
     pub const fn empty() -> Self {
         Self {
             account: None,
+            subgroup: None,
             resources: Err(Error::Disabled),
             buffs: Err(Error::Disabled),
         }
     }
+
+    /// Checks whether the group member slot is occupied.
+    #[inline]
+    pub fn is_present(&self) -> bool {
+        self.account.is_some()
+    }
+
+    /// Returns the account name without the leading colon.
+    #[inline]
+    pub fn account_name(&self) -> Option<&str> {
+        self.account
+            .as_deref()
+            .map(|acc| acc.strip_prefix(':').unwrap_or(acc))
+    }
 }
 
 impl Default for GroupMember {
@@ -1,8 +1,15 @@
 mod member;
+mod roster;
 
-pub use self::member::*;
+pub use self::{member::*, roster::*};
 
+use crate::{
+    colors::{Color, Colored},
+    named::Named,
+};
+use enumflags2::bitflags;
 use serde::{Deserialize, Serialize};
+use std::mem;
 use strum::{AsRefStr, Display, EnumIter, IntoStaticStr, VariantArray};
 
 /// Group info.
@@ -11,21 +18,99 @@ pub struct GroupInfo {
     /// Group type.
     pub group_type: GroupType,
 
-    /// Group members.
-    pub members: [GroupMember; 4],
+    /// Squad subgroup of the player.
+    pub subgroup: Option<u8>,
+
+    /// Group members, excluding the player.
+    ///
+    /// In a squad this contains all squad members.
+    pub members: Vec<GroupMember>,
 }
 
 impl GroupInfo {
-    /// Maximum number of displayed members.
-    pub const MAX_MEMBERS: usize = 4;
+    /// Maximum number of party members, excluding the player.
+    pub const MAX_PARTY_MEMBERS: usize = 4;
 
     /// Creates an empty group.
     #[inline]
     pub const fn empty() -> Self {
         Self {
             group_type: GroupType::Solo,
-            members: [const { GroupMember::empty() }; Self::MAX_MEMBERS],
+            subgroup: None,
+            members: Vec::new(),
+        }
+    }
+
+    /// Checks whether the group is a squad.
+    #[inline]
+    pub fn is_squad(&self) -> bool {
+        self.group_type == GroupType::Squad
+    }
+
+    /// Checks whether the member is in the party of the player.
+    ///
+    /// In a squad this is the subgroup of the player.
+    #[inline]
+    pub fn is_party_member(&self, member: &GroupMember) -> bool {
+        !self.is_squad() || member.subgroup == self.subgroup
+    }
+
+    /// Returns the members in the party of the player.
+    pub fn party_members(&self) -> impl Iterator<Item = &GroupMember> {
+        self.members
+            .iter()
+            .filter(|member| self.is_party_member(member))
+    }
+
+    /// Returns the party member with the given index.
+    #[inline]
+    pub fn party_member(&self, index: usize) -> Option<&GroupMember> {
+        self.party_members().nth(index)
+    }
+
+    /// Updates the group type and member identities.
+    ///
+    /// Identities are given as account name and squad subgroup.
+    /// Existing members keep their state when their account is still present.
+    /// Returns `true` if the group composition changed.
+    pub fn update_identity(
+        &mut self,
+        group_type: GroupType,
+        subgroup: Option<u8>,
+        identities: impl IntoIterator<Item = (String, Option<u8>)>,
+    ) -> bool {
+        let identities = identities.into_iter().collect::<Vec<_>>();
+        let changed = self.group_type != group_type
+            || self.subgroup != subgroup
+            || self.members.len() != identities.len()
+            || self
+                .members
+                .iter()
+                .zip(&identities)
+                .any(|(member, (account, subgroup))| {
+                    member.account.as_ref() != Some(account) || member.subgroup != *subgroup
+                });
+
+        self.group_type = group_type;
+        self.subgroup = subgroup;
+        if changed {
+            let mut previous = mem::take(&mut self.members);
+            self.members = identities
+                .into_iter()
+                .map(|(account, subgroup)| {
+                    let mut member = previous
+                        .iter()
+                        .position(|member| member.account.as_ref() == Some(&account))
+                        .map(|index| previous.swap_remove(index))
+                        .unwrap_or_default();
+                    member.account = Some(account);
+                    member.subgroup = subgroup;
+                    member
+                })
+                .collect();
         }
+
+        changed
     }
 }
 
@@ -53,8 +138,73 @@ impl Default for GroupInfo {
     Serialize,
     Deserialize,
 )]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[bitflags]
+#[repr(u8)]
 pub enum GroupType {
-    Solo,
-    Party,
-    Squad,
+    Solo = 1 << 0,
+    Party = 1 << 1,
+    Squad = 1 << 2,
+}
+
+impl Named for GroupType {
+    fn name(&self) -> &'static str {
+        self.into()
+    }
+
+    fn short_name(&self) -> &'static str {
+        self.name()
+    }
+}
+
+impl Colored for GroupType {
+    fn colored(&self) -> Option<Color> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identities(accounts: &[(&str, u8)]) -> Vec<(String, Option<u8>)> {
+        accounts
+            .iter()
+            .map(|(account, subgroup)| (account.to_string(), Some(*subgroup)))
+            .collect()
+    }
+
+    #[test]
+    fn squad_identity() {
+        let mut group = GroupInfo::empty();
+        let squad = identities(&[(":A.1", 1), (":B.2", 2), (":C.3", 1), (":D.4", 3)]);
+
+        assert!(group.update_identity(GroupType::Squad, Some(1), squad.clone()));
+        assert_eq!(group.members.len(), 4);
+        assert!(!group.update_identity(GroupType::Squad, Some(1), squad));
+
+        let party = group
+            .party_members()
+            .map(|member| member.account_name())
+            .collect::<Vec<_>>();
+        assert_eq!(party, [Some("A.1"), Some("C.3")]);
+        assert_eq!(
+            group.party_member(1).and_then(GroupMember::account_name),
+            Some("C.3")
+        );
+
+        assert!(group.update_identity(GroupType::Party, None, identities(&[(":B.2", 0)])));
+        assert_eq!(group.members.len(), 1);
+        assert_eq!(
+            group.party_member(0).and_then(GroupMember::account_name),
+            Some("B.2")
+        );
+
+        // members keep their state when reordered
+        group.members[0].buffs = Ok(Default::default());
+        let reordered = identities(&[(":C.3", 0), (":B.2", 0)]);
+        assert!(group.update_identity(GroupType::Party, None, reordered));
+        assert!(group.members[0].buffs.is_err());
+        assert!(group.members[1].buffs.is_ok());
+    }
 }
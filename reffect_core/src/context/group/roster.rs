@@ -0,0 +1,223 @@
+use std::ffi::{CStr, c_char};
+
+/// Squad roster tracked from squad update events.
+#[derive(Debug, Clone)]
+pub struct SquadRoster {
+    /// Account name of the player.
+    account: Option<String>,
+
+    /// Squad users including the player with their subgroup.
+    users: Vec<(String, Option<u8>)>,
+}
+
+impl SquadRoster {
+    /// Nexus event identifier for squad updates from Unofficial Extras.
+    pub const UPDATE_EVENT: &'static str = "EV_UNOFFICIAL_EXTRAS_SQUAD_UPDATE";
+
+    /// Nexus event identifier for the account name of the player.
+    pub const ACCOUNT_EVENT: &'static str = "EV_ACCOUNT_NAME";
+
+    /// Nexus event identifier to request the account name of the player.
+    pub const REQUEST_ACCOUNT_EVENT: &'static str = "EV_REQUEST_ACCOUNT_NAME";
+
+    /// Creates an empty roster.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            account: None,
+            users: Vec::new(),
+        }
+    }
+
+    /// Checks whether the roster is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty()
+    }
+
+    /// Sets the account name of the player.
+    ///
+    /// Returns `true` if the account name changed.
+    pub fn set_account(&mut self, account: &str) -> bool {
+        let account = strip_account(account);
+        if self.account.as_deref() != Some(account) {
+            self.account = Some(account.into());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Checks whether the account is the player.
+    #[inline]
+    fn is_player(&self, account: &str) -> bool {
+        self.account.as_deref() == Some(account)
+    }
+
+    /// Updates a user in the roster, [`None`] as subgroup removes the user.
+    ///
+    /// Removing the player clears the roster.
+    /// Returns `true` if the roster changed.
+    pub fn update(&mut self, account: &str, subgroup: Option<Option<u8>>) -> bool {
+        let account = strip_account(account);
+        let index = self.users.iter().position(|(user, _)| user == account);
+        match (subgroup, index) {
+            (Some(subgroup), Some(index)) => {
+                let entry = &mut self.users[index].1;
+                let changed = *entry != subgroup;
+                *entry = subgroup;
+                changed
+            }
+            (Some(subgroup), None) => {
+                self.users.push((account.into(), subgroup));
+                true
+            }
+            (None, _) if self.is_player(account) => {
+                let changed = !self.users.is_empty();
+                self.users.clear();
+                changed
+            }
+            (None, Some(index)) => {
+                self.users.remove(index);
+                true
+            }
+            (None, None) => false,
+        }
+    }
+
+    /// Returns the subgroup of the player.
+    pub fn subgroup(&self) -> Option<u8> {
+        self.users
+            .iter()
+            .find(|(user, _)| self.is_player(user))
+            .and_then(|(_, subgroup)| *subgroup)
+    }
+
+    /// Returns the identities of the other squad members.
+    pub fn members(&self) -> impl Iterator<Item = (String, Option<u8>)> + '_ {
+        self.users
+            .iter()
+            .filter(|(user, _)| !self.is_player(user))
+            .map(|(user, subgroup)| (format!(":{user}"), *subgroup))
+    }
+}
+
+impl Default for SquadRoster {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Strips the leading colon from an account name.
+fn strip_account(account: &str) -> &str {
+    account.strip_prefix(':').unwrap_or(account)
+}
+
+/// Payload of Unofficial Extras squad update events.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct SquadUpdate {
+    /// Updated users.
+    pub users: *const SquadUser,
+
+    /// Number of updated users.
+    pub count: u64,
+}
+
+impl SquadUpdate {
+    /// Returns the updated users.
+    ///
+    /// # Safety
+    /// The users pointer has to be null or point to `count` valid users.
+    pub unsafe fn users(&self) -> &[SquadUser] {
+        if self.users.is_null() {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(self.users, self.count as usize) }
+        }
+    }
+}
+
+/// Squad user information from Unofficial Extras.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct SquadUser {
+    /// Null-terminated UTF-8 account name.
+    pub account: *const c_char,
+
+    /// Time the user joined the squad.
+    pub join_time: i64,
+
+    /// User role in the squad.
+    pub role: u8,
+
+    /// Zero-based subgroup.
+    pub subgroup: u8,
+
+    /// Ready check status.
+    pub ready: bool,
+
+    _unused1: u8,
+    _unused2: u32,
+}
+
+impl SquadUser {
+    /// Role of users no longer in the squad.
+    pub const ROLE_NONE: u8 = 5;
+
+    /// Role of invited users.
+    pub const ROLE_INVITED: u8 = 3;
+
+    /// Role of users applying to join.
+    pub const ROLE_APPLIED: u8 = 4;
+
+    /// Returns the account name.
+    ///
+    /// # Safety
+    /// The account pointer has to be null or point to a valid null-terminated string.
+    pub unsafe fn account(&self) -> Option<&str> {
+        if self.account.is_null() {
+            None
+        } else {
+            unsafe { CStr::from_ptr(self.account) }.to_str().ok()
+        }
+    }
+
+    /// Returns the one-based subgroup if the user is a member of the squad.
+    ///
+    /// Users no longer in the squad, invited and applying users are [`None`].
+    pub fn member_subgroup(&self) -> Option<Option<u8>> {
+        match self.role {
+            Self::ROLE_INVITED | Self::ROLE_APPLIED => None,
+            role if role >= Self::ROLE_NONE => None,
+            _ => Some(Some(self.subgroup + 1)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roster() {
+        let mut roster = SquadRoster::new();
+        assert!(roster.set_account(":A.1"));
+        assert!(roster.update("A.1", Some(Some(1))));
+        assert!(roster.update(":B.2", Some(Some(2))));
+        assert!(!roster.update(":B.2", Some(Some(2))));
+        assert!(roster.update(":C.3", Some(Some(1))));
+
+        assert_eq!(roster.subgroup(), Some(1));
+        assert_eq!(
+            roster.members().collect::<Vec<_>>(),
+            [(":B.2".into(), Some(2)), (":C.3".into(), Some(1))]
+        );
+
+        assert!(roster.update(":B.2", None));
+        assert_eq!(roster.members().count(), 1);
+
+        assert!(roster.update(":A.1", None));
+        assert!(roster.is_empty());
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// User-defined named map group.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomMapGroup {
+    /// Stable group id, referenced by map filters.
+    pub id: u32,
+
+    /// Group name.
+    pub name: String,
+
+    /// Map ids.
+    pub ids: Vec<u32>,
+}
+
+impl CustomMapGroup {
+    /// Creates a new empty map group with the given id and name.
+    pub fn new(id: u32, name: impl Into<String>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            ids: Vec::new(),
+        }
+    }
+
+    /// Checks whether the group contains the given map id.
+    pub fn contains(&self, id: u32) -> bool {
+        self.ids.contains(&id)
+    }
+}
@@ -0,0 +1,228 @@
+mod custom;
+mod ids;
+
+pub use self::{custom::*, ids::*};
+
+use crate::{
+    colors::{Color, Colored},
+    named::Named,
+};
+use enumflags2::{BitFlags, bitflags, make_bitflags};
+use nexus::data_link::mumble::{MumblePtr, map_type};
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumIter, IntoStaticStr, VariantArray};
+
+#[derive(Debug, Clone)]
+pub struct MapInfo {
+    pub id: u32,
+    pub category: BitFlags<MapCategory>,
+
+    /// Player position in map units (inches).
+    ///
+    /// Axes are `x` east, `y` up and `z` north.
+    pub position: [f32; 3],
+}
+
+impl MapInfo {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self {
+            id: 0,
+            category: make_bitflags!(MapCategory::Other),
+            position: [0.0; 3],
+        }
+    }
+
+    pub fn update(&mut self, mumble: MumblePtr) -> bool {
+        let id = mumble.read_map_id();
+        let map_type = mumble.read_map_type();
+        let new = self.id != id;
+
+        self.id = id;
+        self.category = MapCategory::new(id, map_type);
+
+        new
+    }
+
+    /// Updates the player position from the Mumble avatar position.
+    ///
+    /// Returns `true` if the position changed.
+    pub fn update_position(&mut self, mumble: MumblePtr) -> bool {
+        const INCHES_PER_METER: f32 = 39.3701;
+
+        let position = mumble.read_avatar().position;
+        let position = [position.x, position.y, position.z].map(|value| value * INCHES_PER_METER);
+        let changed = self.position != position;
+        self.position = position;
+        changed
+    }
+
+    /// Returns the horizontal player position.
+    #[inline]
+    pub fn position_2d(&self) -> [f32; 2] {
+        let [x, _, z] = self.position;
+        [x, z]
+    }
+
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.id != 0
+    }
+
+    #[inline]
+    pub fn is_on_map(&self, id: u32) -> bool {
+        self.id == id
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    AsRefStr,
+    IntoStaticStr,
+    Display,
+    EnumIter,
+    VariantArray,
+    Serialize,
+    Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[repr(u16)]
+#[bitflags]
+pub enum MapCategory {
+    #[default]
+    #[serde(alias = "Pve")]
+    PvE = 1 << 0,
+
+    #[serde(alias = "Pvp")]
+    PvP = 1 << 1,
+
+    #[serde(alias = "Wvw")]
+    WvW = 1 << 2,
+
+    Instance = 1 << 3,
+
+    #[serde(alias = "Unknown")]
+    Other = 1 << 4,
+
+    #[strum(serialize = "Open World")]
+    OpenWorld = 1 << 5,
+
+    Hub = 1 << 6,
+
+    Raid = 1 << 7,
+
+    Fractal = 1 << 8,
+
+    Strike = 1 << 9,
+
+    Convergence = 1 << 10,
+}
+
+impl MapCategory {
+    /// Resolves the categories for the given map id and map type.
+    ///
+    /// Content categories are added on top of the category of the map type.
+    pub fn new(id: u32, map_type: u32) -> BitFlags<Self> {
+        let category = Self::from_map_type(map_type);
+        match MapGroup::find(id) {
+            Some(group) => category | group.category,
+            None if category == Self::PvE => category | Self::OpenWorld,
+            None => category.into(),
+        }
+    }
+
+    /// Resolves the category of the given map type.
+    pub fn from_map_type(map_type: u32) -> Self {
+        match map_type {
+            map_type::PVE | map_type::PVE_MINI => Self::PvE,
+
+            map_type::PVP | map_type::USER_TOURNAMENT | map_type::BIG_BATTLE => Self::PvP,
+
+            map_type::INSTANCE => Self::Instance,
+
+            map_type::GVG
+            | map_type::WVW_ETERNAL_BATTLEGROUNDS
+            | map_type::WVW_BLUE_BORDERLANDS
+            | map_type::WVW_GREEN_BORDERLANDS
+            | map_type::WVW_RED_BORDERLANDS
+            | map_type::WVW_REWARD
+            | map_type::WVW_OBSIDIAN_SANCTUM
+            | map_type::WVW_EDGE_OF_THE_MISTS
+            | map_type::WVW_LOUNGE => Self::WvW,
+
+            _ => Self::Other,
+        }
+    }
+
+    pub const fn non_competitive() -> BitFlags<Self> {
+        make_bitflags!(Self::{PvE | Instance | Other})
+    }
+}
+
+impl Named for MapCategory {
+    fn name(&self) -> &'static str {
+        self.into()
+    }
+
+    fn short_name(&self) -> &'static str {
+        match self {
+            MapCategory::PvE | MapCategory::PvP | MapCategory::WvW => self.name(),
+            MapCategory::Instance => "Inst",
+            MapCategory::Other => "Other",
+            MapCategory::OpenWorld => "OW",
+            MapCategory::Hub => "Hub",
+            MapCategory::Raid => "Raid",
+            MapCategory::Fractal => "Frac",
+            MapCategory::Strike => "Strk",
+            MapCategory::Convergence => "Conv",
+        }
+    }
+}
+
+impl Colored for MapCategory {
+    fn colored(&self) -> Option<Color> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nexus::data_link::mumble::map_id::{fractal, hub, raid};
+
+    #[test]
+    fn category() {
+        assert_eq!(
+            MapCategory::new(fractal::NIGHTMARE, map_type::INSTANCE),
+            MapCategory::Instance | MapCategory::Fractal
+        );
+        assert_eq!(
+            MapCategory::new(raid::COLD_WAR, map_type::INSTANCE),
+            MapCategory::Instance | MapCategory::Strike
+        );
+        assert_eq!(
+            MapCategory::new(hub::LIONS_ARCH, map_type::PVE),
+            MapCategory::PvE | MapCategory::Hub
+        );
+        assert_eq!(
+            MapCategory::new(convergence::OUTER_NAYOS, map_type::INSTANCE),
+            MapCategory::Instance | MapCategory::Convergence
+        );
+        assert_eq!(
+            MapCategory::new(15, map_type::PVE),
+            MapCategory::PvE | MapCategory::OpenWorld
+        );
+        assert_eq!(
+            MapCategory::new(15, map_type::PVP),
+            BitFlags::from(MapCategory::PvP)
+        );
+    }
+}
@@ -5,17 +5,23 @@ mod item;
 mod map;
 mod player;
 mod resource;
+mod schedule;
 mod skill;
 mod target;
+mod time;
+mod timer;
+mod toggle;
 mod ui;
 mod updates;
+mod variable;
 
 pub use self::{
-    combatant::*, edit::*, group::*, item::*, map::*, player::*, resource::*, skill::*, target::*,
-    ui::*, updates::*,
+    combatant::*, edit::*, group::*, item::*, map::*, player::*, resource::*, schedule::*,
+    skill::*, target::*, time::*, timer::*, toggle::*, ui::*, updates::*, variable::*,
 };
 
 use crate::{error::Error, links::Links, profiling::measure, worker::StoppableWorker};
+use chrono::Utc;
 use std::{
     sync::{Mutex, MutexGuard},
     thread,
@@ -28,6 +34,9 @@ pub struct Context {
     /// Current system time.
     pub now: u32,
 
+    /// Information about real-world time.
+    pub time: TimeInfo,
+
     /// Pending updates.
     pub updates: Updates,
 
@@ -40,6 +49,21 @@ pub struct Context {
     /// Information about current map.
     pub map: MapInfo,
 
+    /// User-defined map groups.
+    pub map_groups: Vec<CustomMapGroup>,
+
+    /// User-defined scheduled events.
+    pub events: Vec<CustomEvent>,
+
+    /// User-defined keybind toggles.
+    pub toggles: Toggles,
+
+    /// User-defined timers.
+    pub timers: Vec<CustomTimer>,
+
+    /// Variables set externally via Nexus events.
+    pub variables: Variables,
+
     /// Information about player character.
     pub player: PlayerInfo,
 
@@ -48,6 +72,9 @@ pub struct Context {
 
     /// Information about current group.
     pub group: Result<GroupInfo, Error>,
+
+    /// Squad roster from squad update events.
+    pub roster: SquadRoster,
 }
 
 impl Context {
@@ -55,13 +82,20 @@ impl Context {
     pub const fn disabled() -> Self {
         Self {
             now: 0,
+            time: TimeInfo::empty(),
             updates: Updates::EMPTY,
             edit: EditState::new(),
             ui: UiInfo::new(),
             map: MapInfo::empty(),
+            map_groups: Vec::new(),
+            events: Vec::new(),
+            toggles: Toggles::new(),
+            variables: Variables::new(),
+            timers: Vec::new(),
             player: PlayerInfo::disabled(),
             target: TargetInfo::disabled(),
             group: Err(Error::Disabled),
+            roster: SquadRoster::new(),
         }
     }
 
@@ -69,13 +103,20 @@ impl Context {
     pub const fn empty() -> Self {
         Self {
             now: 0,
+            time: TimeInfo::empty(),
             updates: Updates::EMPTY,
             edit: EditState::new(),
             ui: UiInfo::new(),
             map: MapInfo::empty(),
+            map_groups: Vec::new(),
+            events: Vec::new(),
+            toggles: Toggles::new(),
+            variables: Variables::new(),
+            timers: Vec::new(),
             player: PlayerInfo::empty(),
             target: TargetInfo::empty(),
             group: Ok(GroupInfo::empty()),
+            roster: SquadRoster::new(),
         }
     }
 
@@ -115,6 +156,10 @@ impl Context {
                     match mumble.parse_identity() {
                         Ok(identity) => {
                             self.updates.insert(Update::PlayerIdentity);
+                            if self.ui.commander != identity.commander {
+                                self.ui.commander = identity.commander;
+                                self.updates.insert(Update::Ui);
+                            }
                             self.player.update_identity(identity);
                         }
                         Err(err) => log::error!("Failed to parse mumble identity: {err}"),
@@ -123,6 +168,7 @@ impl Context {
                     let map_changed = self.map.update(mumble);
                     if map_changed {
                         self.updates.insert(Update::Map);
+                        self.reset_timers();
                     }
                 }
             },
@@ -134,9 +180,28 @@ impl Context {
     #[inline]
     pub fn prepare_render(&mut self, links: &Links) {
         self.now = unsafe { timeGetTime() };
-        self.ui.update(links);
+        if self.time.update(Utc::now().timestamp_millis()) {
+            self.updates.insert(Update::Time);
+        }
+        if self.variables.remove_expired(self.now) {
+            self.updates.insert(Update::Variables);
+        }
+        let now = self.now;
+        if self
+            .timers
+            .iter_mut()
+            .fold(false, |expired, timer| timer.expire(now) | expired)
+        {
+            self.updates.insert(Update::Timers);
+        }
+        if self.ui.update(links) {
+            self.updates.insert(Update::Ui);
+        }
         if let Some(mumble) = links.mumble() {
             self.player.update_fast(mumble);
+            if self.map.update_position(mumble) {
+                self.updates.insert(Update::Position);
+            }
         }
     }
 
@@ -190,6 +255,156 @@ impl Context {
         log::trace!("Forcing all updates");
     }
 
+    /// Returns the user-defined map group with the given id.
+    #[inline]
+    pub fn map_group(&self, id: u32) -> Option<&CustomMapGroup> {
+        self.map_groups.iter().find(|group| group.id == id)
+    }
+
+    /// Returns an unused id for a new user-defined map group.
+    #[inline]
+    pub fn next_map_group_id(&self) -> u32 {
+        self.map_groups
+            .iter()
+            .map(|group| group.id)
+            .max()
+            .unwrap_or(0)
+            + 1
+    }
+
+    /// Checks whether the current map is in the user-defined map group with the given id.
+    #[inline]
+    pub fn is_on_map_group(&self, id: u32) -> bool {
+        self.map_group(id)
+            .is_some_and(|group| group.contains(self.map.id))
+    }
+
+    /// Returns the schedule of the event with the given name.
+    ///
+    /// User-defined events take precedence over built-in events.
+    pub fn event_schedule(&self, name: &str) -> Option<Schedule> {
+        match self.events.iter().find(|event| event.name == name) {
+            Some(event) => event.parsed_schedule(),
+            None => ScheduledEvent::find(name).map(|event| event.schedule),
+        }
+    }
+
+    /// Updates the parsed schedules of user-defined events.
+    pub fn update_events(&mut self) {
+        for event in &mut self.events {
+            event.update_parsed();
+        }
+    }
+
+    /// Sets the external variable with the given name.
+    pub fn set_variable(&mut self, name: impl Into<String>, value: f32, duration: u32) {
+        self.variables
+            .set(name, Variable::new(value, duration, self.now));
+        self.updates.insert(Update::Variables);
+    }
+
+    /// Removes the external variable with the given name.
+    pub fn remove_variable(&mut self, name: &str) {
+        if self.variables.remove(name) {
+            self.updates.insert(Update::Variables);
+        }
+    }
+
+    /// Returns the user-defined timer with the given name.
+    #[inline]
+    pub fn timer(&self, name: &str) -> Option<&CustomTimer> {
+        self.timers.iter().find(|timer| timer.name == name)
+    }
+
+    /// Starts or restarts the user-defined timer with the given name.
+    pub fn start_timer(&mut self, name: &str) {
+        let now = self.now;
+        if let Some(timer) = self.timers.iter_mut().find(|timer| timer.name == name) {
+            timer.start(now);
+            self.updates.insert(Update::Timers);
+        }
+    }
+
+    /// Cancels the user-defined timer with the given name.
+    pub fn cancel_timer(&mut self, name: &str) {
+        if let Some(timer) = self.timers.iter_mut().find(|timer| timer.name == name) {
+            timer.cancel();
+            self.updates.insert(Update::Timers);
+        }
+    }
+
+    /// Cancels all user-defined timers.
+    pub fn reset_timers(&mut self) {
+        for timer in &mut self.timers {
+            timer.cancel();
+        }
+        self.updates.insert(Update::Timers);
+    }
+
+    /// Returns the keybind identifiers for toggles, profiles & timers.
+    pub fn keybind_ids(&self) -> impl Iterator<Item = String> + '_ {
+        let timers = self.timers.iter().flat_map(|timer| {
+            [
+                format!("{}{}", CustomTimer::START_PREFIX, timer.name),
+                format!("{}{}", CustomTimer::CANCEL_PREFIX, timer.name),
+            ]
+        });
+        self.toggles.keybind_ids().chain(timers)
+    }
+
+    /// Handles the keybind with the given identifier.
+    pub fn handle_keybind(&mut self, id: &str) {
+        if let Some(name) = id.strip_prefix(CustomTimer::START_PREFIX) {
+            self.start_timer(name);
+        } else if let Some(name) = id.strip_prefix(CustomTimer::CANCEL_PREFIX) {
+            self.cancel_timer(name);
+        } else if self.toggles.handle_keybind(id) {
+            self.updates.insert(Update::Toggles);
+        }
+    }
+
+    /// Sets the account name of the player.
+    pub fn set_account_name(&mut self, account: &str) {
+        if self.roster.set_account(account) {
+            self.apply_roster();
+        }
+    }
+
+    /// Updates a squad member, [`None`] as subgroup removes the member.
+    pub fn update_squad_member(&mut self, account: &str, subgroup: Option<Option<u8>>) {
+        if self.roster.update(account, subgroup) {
+            self.apply_roster();
+        }
+    }
+
+    /// Applies the squad roster to the group member identities.
+    ///
+    /// Party & solo state outside of squads is left to the internal updates.
+    fn apply_roster(&mut self) {
+        if !self.roster.is_empty() {
+            let subgroup = self.roster.subgroup();
+            let members = self.roster.members().collect::<Vec<_>>();
+            self.update_group_identity(GroupType::Squad, subgroup, members);
+        }
+    }
+
+    /// Updates the group member identities.
+    pub fn update_group_identity(
+        &mut self,
+        group_type: GroupType,
+        subgroup: Option<u8>,
+        identities: impl IntoIterator<Item = (String, Option<u8>)>,
+    ) {
+        if self.group.is_err() {
+            self.group = Ok(GroupInfo::empty());
+        }
+        if let Ok(group) = &mut self.group
+            && group.update_identity(group_type, subgroup, identities)
+        {
+            self.updates.insert(Update::GroupIdentity);
+        }
+    }
+
     #[inline]
     pub fn set_error(&mut self, error: Error) {
         self.player.set_error(error.clone());
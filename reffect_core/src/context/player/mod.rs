@@ -4,6 +4,7 @@ mod mount;
 mod profession;
 mod race;
 mod resources;
+mod spec_line;
 mod specialization;
 
 use crate::{
@@ -13,11 +14,15 @@ use crate::{
 use nexus::data_link::mumble::{Identity, MumblePtr};
 
 pub use self::{
-    build::*, gear::*, mount::*, profession::*, race::*, resources::*, specialization::*,
+    build::*, gear::*, mount::*, profession::*, race::*, resources::*, spec_line::*,
+    specialization::*,
 };
 
 #[derive(Debug, Clone)]
 pub struct PlayerInfo {
+    /// Player character name.
+    pub name: String,
+
     /// Player race.
     pub race: Result<Race, u8>,
 
@@ -50,6 +55,7 @@ impl PlayerInfo {
     #[inline]
     pub const fn disabled() -> Self {
         Self {
+            name: String::new(),
             prof: Err(0),
             spec: Err(0),
             race: Err(0),
@@ -65,6 +71,7 @@ impl PlayerInfo {
     #[inline]
     pub const fn empty() -> Self {
         Self {
+            name: String::new(),
             prof: Err(0),
             spec: Err(0),
             race: Err(0),
@@ -87,6 +94,7 @@ impl PlayerInfo {
         self.race = (identity.race as u8).try_into();
         self.prof = Profession::try_from(identity.profession as u8);
         self.spec = Specialization::try_from(self.prof.ok(), identity.spec).ok_or(identity.spec);
+        self.name = identity.name;
     }
 
     #[inline]
@@ -62,6 +62,21 @@ impl TryFrom<u8> for Profession {
 }
 
 impl Profession {
+    /// Returns the profession id, same as in Mumble & chat links.
+    pub const fn id(&self) -> u8 {
+        match self {
+            Self::Guardian => 1,
+            Self::Warrior => 2,
+            Self::Engineer => 3,
+            Self::Ranger => 4,
+            Self::Thief => 5,
+            Self::Elementalist => 6,
+            Self::Mesmer => 7,
+            Self::Necromancer => 8,
+            Self::Revenant => 9,
+        }
+    }
+
     pub fn specializations(&self) -> BitFlags<Specialization> {
         match self {
             Self::Guardian => {
@@ -1,3 +1,7 @@
+use crate::{
+    colors::{Color, Colored},
+    named::Named,
+};
 use enumflags2::bitflags;
 use serde::{Deserialize, Serialize};
 use strum::{AsRefStr, Display, EnumIter, IntoStaticStr, VariantArray};
@@ -19,6 +23,7 @@ use strum::{AsRefStr, Display, EnumIter, IntoStaticStr, VariantArray};
     Serialize,
     Deserialize,
 )]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[bitflags]
 #[repr(u8)]
 pub enum Race {
@@ -43,3 +48,19 @@ impl TryFrom<u8> for Race {
         }
     }
 }
+
+impl Named for Race {
+    fn name(&self) -> &'static str {
+        self.into()
+    }
+
+    fn short_name(&self) -> &'static str {
+        self.name()
+    }
+}
+
+impl Colored for Race {
+    fn colored(&self) -> Option<Color> {
+        None
+    }
+}
@@ -0,0 +1,121 @@
+use super::{Profession, Specialization};
+use crate::named::Named;
+
+/// Specialization line (trait line).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpecLine {
+    /// Specialization id, same as in GW2 API.
+    pub id: u32,
+
+    /// Specialization name.
+    pub name: &'static str,
+
+    /// Profession of the specialization.
+    pub prof: Profession,
+}
+
+impl SpecLine {
+    /// Core specialization lines.
+    pub const CORE: &'static [Self] = &[
+        Self::new(13, "Valor", Profession::Guardian),
+        Self::new(16, "Radiance", Profession::Guardian),
+        Self::new(42, "Zeal", Profession::Guardian),
+        Self::new(46, "Virtues", Profession::Guardian),
+        Self::new(49, "Honor", Profession::Guardian),
+        Self::new(4, "Strength", Profession::Warrior),
+        Self::new(11, "Tactics", Profession::Warrior),
+        Self::new(22, "Defense", Profession::Warrior),
+        Self::new(36, "Arms", Profession::Warrior),
+        Self::new(51, "Discipline", Profession::Warrior),
+        Self::new(6, "Explosives", Profession::Engineer),
+        Self::new(21, "Tools", Profession::Engineer),
+        Self::new(29, "Alchemy", Profession::Engineer),
+        Self::new(38, "Firearms", Profession::Engineer),
+        Self::new(47, "Inventions", Profession::Engineer),
+        Self::new(8, "Marksmanship", Profession::Ranger),
+        Self::new(25, "Nature Magic", Profession::Ranger),
+        Self::new(30, "Skirmishing", Profession::Ranger),
+        Self::new(32, "Beastmastery", Profession::Ranger),
+        Self::new(33, "Wilderness Survival", Profession::Ranger),
+        Self::new(20, "Shadow Arts", Profession::Thief),
+        Self::new(28, "Deadly Arts", Profession::Thief),
+        Self::new(35, "Critical Strikes", Profession::Thief),
+        Self::new(44, "Trickery", Profession::Thief),
+        Self::new(54, "Acrobatics", Profession::Thief),
+        Self::new(17, "Water", Profession::Elementalist),
+        Self::new(26, "Earth", Profession::Elementalist),
+        Self::new(31, "Fire", Profession::Elementalist),
+        Self::new(37, "Arcana", Profession::Elementalist),
+        Self::new(41, "Air", Profession::Elementalist),
+        Self::new(1, "Dueling", Profession::Mesmer),
+        Self::new(10, "Domination", Profession::Mesmer),
+        Self::new(23, "Inspiration", Profession::Mesmer),
+        Self::new(24, "Illusions", Profession::Mesmer),
+        Self::new(45, "Chaos", Profession::Mesmer),
+        Self::new(2, "Death Magic", Profession::Necromancer),
+        Self::new(19, "Blood Magic", Profession::Necromancer),
+        Self::new(39, "Curses", Profession::Necromancer),
+        Self::new(50, "Soul Reaping", Profession::Necromancer),
+        Self::new(53, "Spite", Profession::Necromancer),
+        Self::new(3, "Invocation", Profession::Revenant),
+        Self::new(9, "Retribution", Profession::Revenant),
+        Self::new(12, "Salvation", Profession::Revenant),
+        Self::new(14, "Corruption", Profession::Revenant),
+        Self::new(15, "Devastation", Profession::Revenant),
+    ];
+
+    /// Creates a new specialization line.
+    pub const fn new(id: u32, name: &'static str, prof: Profession) -> Self {
+        Self { id, name, prof }
+    }
+
+    /// Finds the core or elite specialization line with the given id.
+    pub fn find(id: u32) -> Option<Self> {
+        Self::CORE
+            .iter()
+            .copied()
+            .find(|line| line.id == id)
+            .or_else(|| Specialization::try_from(None, id).map(|spec| Self::elite(id, spec)))
+    }
+
+    /// Returns all core & elite specialization lines of the profession.
+    pub fn for_prof(prof: Profession) -> impl Iterator<Item = Self> {
+        let core = Self::CORE
+            .iter()
+            .copied()
+            .filter(move |line| line.prof == prof);
+        let elite = Specialization::ELITES
+            .iter()
+            .filter(move |(_, spec)| spec.profession() == prof)
+            .map(|(id, spec)| Self::elite(*id, *spec));
+        core.chain(elite)
+    }
+
+    /// Creates the specialization line for an elite specialization.
+    fn elite(id: u32, spec: Specialization) -> Self {
+        Self::new(id, spec.name(), spec.profession())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::VariantArray;
+
+    #[test]
+    fn lines() {
+        assert_eq!(
+            SpecLine::find(31),
+            Some(SpecLine::new(31, "Fire", Profession::Elementalist))
+        );
+        assert_eq!(
+            SpecLine::find(62),
+            Some(SpecLine::new(62, "Firebrand", Profession::Guardian))
+        );
+        assert_eq!(SpecLine::find(0), None);
+
+        for prof in Profession::VARIANTS {
+            assert_eq!(SpecLine::for_prof(*prof).count(), 5 + 4, "{prof}");
+        }
+    }
+}
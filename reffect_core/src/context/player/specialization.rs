@@ -93,46 +93,60 @@ pub enum Specialization {
 }
 
 impl Specialization {
+    /// Elite specializations with their specialization id.
+    pub const ELITES: &'static [(u32, Self)] = &[
+        (5, Self::Druid),
+        (7, Self::Daredevil),
+        (18, Self::Berserker),
+        (27, Self::Dragonhunter),
+        (34, Self::Reaper),
+        (40, Self::Chronomancer),
+        (43, Self::Scrapper),
+        (48, Self::Tempest),
+        (52, Self::Herald),
+        (55, Self::Soulbeast),
+        (56, Self::Weaver),
+        (57, Self::Holosmith),
+        (58, Self::Deadeye),
+        (59, Self::Mirage),
+        (60, Self::Scourge),
+        (61, Self::Spellbreaker),
+        (62, Self::Firebrand),
+        (63, Self::Renegade),
+        (64, Self::Harbinger),
+        (65, Self::Willbender),
+        (66, Self::Virtuoso),
+        (67, Self::Catalyst),
+        (68, Self::Bladesworn),
+        (69, Self::Vindicator),
+        (70, Self::Mechanist),
+        (71, Self::Specter),
+        (72, Self::Untamed),
+        (73, Self::Troubadour),
+        (74, Self::Paragon),
+        (75, Self::Amalgam),
+        (76, Self::Ritualist),
+        (77, Self::Antiquary),
+        (78, Self::Galeshot),
+        (79, Self::Conduit),
+        (80, Self::Evoker),
+        (81, Self::Luminary),
+    ];
+
     pub fn try_from(prof: Option<Profession>, spec: u32) -> Option<Self> {
-        match spec {
-            5 => Some(Self::Druid),
-            7 => Some(Self::Daredevil),
-            18 => Some(Self::Berserker),
-            27 => Some(Self::Dragonhunter),
-            34 => Some(Self::Reaper),
-            40 => Some(Self::Chronomancer),
-            43 => Some(Self::Scrapper),
-            48 => Some(Self::Tempest),
-            52 => Some(Self::Herald),
-            55 => Some(Self::Soulbeast),
-            56 => Some(Self::Weaver),
-            57 => Some(Self::Holosmith),
-            58 => Some(Self::Deadeye),
-            59 => Some(Self::Mirage),
-            60 => Some(Self::Scourge),
-            61 => Some(Self::Spellbreaker),
-            62 => Some(Self::Firebrand),
-            63 => Some(Self::Renegade),
-            64 => Some(Self::Harbinger),
-            65 => Some(Self::Willbender),
-            66 => Some(Self::Virtuoso),
-            67 => Some(Self::Catalyst),
-            68 => Some(Self::Bladesworn),
-            69 => Some(Self::Vindicator),
-            70 => Some(Self::Mechanist),
-            71 => Some(Self::Specter),
-            72 => Some(Self::Untamed),
-            73 => Some(Self::Troubadour),
-            74 => Some(Self::Paragon),
-            75 => Some(Self::Amalgam),
-            76 => Some(Self::Ritualist),
-            77 => Some(Self::Antiquary),
-            78 => Some(Self::Galeshot),
-            79 => Some(Self::Conduit),
-            80 => Some(Self::Evoker),
-            81 => Some(Self::Luminary),
-            _ => prof.map(|prof| prof.into()),
-        }
+        Self::ELITES
+            .iter()
+            .find(|(id, _)| *id == spec)
+            .map(|(_, elite)| *elite)
+            .or_else(|| prof.map(|prof| prof.into()))
+    }
+
+    /// Returns the specialization id for elite specializations.
+    pub fn elite_id(&self) -> Option<u32> {
+        Self::ELITES
+            .iter()
+            .find(|(_, elite)| elite == self)
+            .map(|(id, _)| *id)
     }
 
     pub const fn profession(&self) -> Profession {
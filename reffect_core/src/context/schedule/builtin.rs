@@ -0,0 +1,59 @@
+use super::{HOUR, Schedule, WEEK};
+use chrono::Weekday;
+
+/// Built-in scheduled event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScheduledEvent {
+    /// Event name.
+    pub name: &'static str,
+
+    /// Event schedule.
+    pub schedule: Schedule,
+}
+
+impl ScheduledEvent {
+    /// Built-in scheduled events.
+    pub const BUILTIN: &'static [Self] = &[
+        Self::new("Daily Reset", Schedule::daily(0, 0)),
+        Self::new(
+            "Weekly Reset",
+            Schedule::weekly(Weekday::Mon, 7 * HOUR + 30, 0),
+        ),
+        Self::new("Admiral Taidha Covington", Schedule::every(3 * HOUR, 0, 15)),
+        Self::new("Svanir Shaman Chief", Schedule::every(2 * HOUR, 15, 15)),
+        Self::new("Megadestroyer", Schedule::every(3 * HOUR, 30, 15)),
+        Self::new("Fire Elemental", Schedule::every(2 * HOUR, 45, 15)),
+        Self::new("The Shatterer", Schedule::every(3 * HOUR, HOUR, 15)),
+        Self::new(
+            "Great Jungle Wurm",
+            Schedule::every(2 * HOUR, HOUR + 15, 15),
+        ),
+        Self::new("Modniir Ulgoth", Schedule::every(3 * HOUR, HOUR + 30, 15)),
+        Self::new("Shadow Behemoth", Schedule::every(2 * HOUR, HOUR + 45, 15)),
+        Self::new("Golem Mark II", Schedule::every(3 * HOUR, 2 * HOUR, 15)),
+        Self::new(
+            "Claw of Jormag",
+            Schedule::every(3 * HOUR, 2 * HOUR + 30, 15),
+        ),
+    ];
+
+    /// Creates a new scheduled event.
+    pub const fn new(name: &'static str, schedule: Schedule) -> Self {
+        Self { name, schedule }
+    }
+
+    /// Finds the built-in event with the given name.
+    pub fn find(name: &str) -> Option<&'static Self> {
+        Self::BUILTIN.iter().find(|event| event.name == name)
+    }
+}
+
+const _: () = {
+    // ensure built-in schedules align with the week
+    let mut i = 0;
+    while i < ScheduledEvent::BUILTIN.len() {
+        let schedule = ScheduledEvent::BUILTIN[i].schedule;
+        assert!(WEEK % schedule.period == 0 && schedule.duration <= schedule.period);
+        i += 1;
+    }
+};
@@ -0,0 +1,66 @@
+use super::{Schedule, ScheduleError};
+use serde::{Deserialize, Serialize};
+
+/// User-defined scheduled event.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomEvent {
+    /// Event name.
+    pub name: String,
+
+    /// Event schedule, for example `every 2h at :15, duration 15m`.
+    pub schedule: String,
+
+    /// Parsed event schedule, updated via [`CustomEvent::update_parsed`].
+    #[serde(skip)]
+    parsed: Option<Schedule>,
+}
+
+impl CustomEvent {
+    /// Creates a new event with the given name and a daily schedule.
+    pub fn new(name: impl Into<String>) -> Self {
+        let mut event = Self {
+            name: name.into(),
+            schedule: "daily at 0:00".into(),
+            parsed: None,
+        };
+        event.update_parsed();
+        event
+    }
+
+    /// Parses the event schedule.
+    pub fn parse_schedule(&self) -> Result<Schedule, ScheduleError> {
+        self.schedule.parse()
+    }
+
+    /// Returns the parsed event schedule, if valid.
+    pub fn parsed_schedule(&self) -> Option<Schedule> {
+        self.parsed
+    }
+
+    /// Updates the parsed event schedule after the schedule changed.
+    pub fn update_parsed(&mut self) {
+        self.parsed = self.parse_schedule().ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsed() {
+        let mut event = CustomEvent::new("Test");
+        assert_eq!(event.parsed_schedule(), event.parse_schedule().ok());
+        assert!(event.parsed_schedule().is_some());
+
+        event.schedule = "invalid".into();
+        assert!(event.parsed_schedule().is_some());
+        event.update_parsed();
+        assert_eq!(event.parsed_schedule(), None);
+
+        event.schedule = "every 2h at :15, duration 15m".into();
+        event.update_parsed();
+        assert_eq!(event.parsed_schedule(), event.parse_schedule().ok());
+    }
+}
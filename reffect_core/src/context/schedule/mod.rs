@@ -0,0 +1,209 @@
+mod builtin;
+mod custom;
+mod parse;
+
+pub use self::{builtin::*, custom::*, parse::*};
+
+use chrono::Weekday;
+use std::fmt;
+
+/// Minutes in an hour.
+const HOUR: u32 = 60;
+
+/// Minutes in a day.
+const DAY: u32 = 24 * HOUR;
+
+/// Minutes in a week.
+const WEEK: u32 = 7 * DAY;
+
+/// Milliseconds in a minute.
+const MINUTE_MILLIS: i64 = 60 * 1000;
+
+/// Reference point for schedules, Monday 1970-01-05 00:00 UTC in milliseconds.
+const REFERENCE: i64 = 4 * DAY as i64 * MINUTE_MILLIS;
+
+/// Repeating event schedule in UTC.
+///
+/// All values are in minutes.
+/// Occurrences are aligned to Monday 00:00 UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Schedule {
+    /// Time between occurrences.
+    pub period: u32,
+
+    /// Offset of the first occurrence.
+    pub offset: u32,
+
+    /// Duration of an occurrence.
+    pub duration: u32,
+}
+
+impl Schedule {
+    /// Creates a schedule repeating every period.
+    pub const fn every(period: u32, offset: u32, duration: u32) -> Self {
+        Self {
+            period,
+            offset,
+            duration,
+        }
+    }
+
+    /// Creates a schedule repeating every day at the given minute of the day.
+    pub const fn daily(at: u32, duration: u32) -> Self {
+        Self::every(DAY, at, duration)
+    }
+
+    /// Creates a schedule repeating every week at the given weekday and minute of the day.
+    pub const fn weekly(weekday: Weekday, at: u32, duration: u32) -> Self {
+        Self::every(WEEK, weekday as u32 * DAY + at, duration)
+    }
+
+    /// Returns the duration of an occurrence in milliseconds.
+    pub const fn duration_millis(&self) -> u32 {
+        self.duration * MINUTE_MILLIS as u32
+    }
+
+    /// Returns the time between the end of an occurrence and the start of the next in milliseconds.
+    pub const fn wait_millis(&self) -> u32 {
+        self.period.saturating_sub(self.duration) * MINUTE_MILLIS as u32
+    }
+
+    /// Returns the schedule state at the given UTC timestamp in milliseconds.
+    pub fn state(&self, timestamp: i64) -> ScheduleState {
+        let period = self.period.max(1) as i64 * MINUTE_MILLIS;
+        let offset = self.offset as i64 * MINUTE_MILLIS;
+        let duration = self.duration as i64 * MINUTE_MILLIS;
+
+        let passed = (timestamp - REFERENCE - offset).rem_euclid(period);
+        if passed < duration {
+            ScheduleState::Active {
+                remaining: (duration - passed) as u32,
+            }
+        } else {
+            ScheduleState::Upcoming {
+                remaining: (period - passed) as u32,
+            }
+        }
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.period {
+            WEEK => {
+                let weekday = Weekday::try_from((self.offset / DAY) as u8).unwrap_or(Weekday::Mon);
+                write!(f, "weekly {weekday} at {}", TimeOfDay(self.offset % DAY))?;
+            }
+            DAY => write!(f, "daily at {}", TimeOfDay(self.offset))?,
+            period => write!(f, "every {} at {}", Minutes(period), TimeOfDay(self.offset))?,
+        }
+        if self.duration > 0 {
+            write!(f, ", duration {}", Minutes(self.duration))?;
+        }
+        Ok(())
+    }
+}
+
+/// Current state of a schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScheduleState {
+    /// Occurrence is ongoing, with milliseconds until the end.
+    Active { remaining: u32 },
+
+    /// Waiting for the next occurrence, with milliseconds until the start.
+    Upcoming { remaining: u32 },
+}
+
+/// Helper to format a time of day in minutes.
+struct TimeOfDay(u32);
+
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{:02}", self.0 / HOUR, self.0 % HOUR)
+    }
+}
+
+/// Helper to format a duration in minutes.
+struct Minutes(u32);
+
+impl fmt::Display for Minutes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let days = self.0 / DAY;
+        let hours = self.0 % DAY / HOUR;
+        let minutes = self.0 % HOUR;
+        if days > 0 {
+            write!(f, "{days}d")?;
+        }
+        if hours > 0 {
+            write!(f, "{hours}h")?;
+        }
+        if minutes > 0 || self.0 == 0 {
+            write!(f, "{minutes}m")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2025-01-06 00:00:00 UTC, a Monday.
+    const MONDAY: i64 = 1_736_121_600_000;
+
+    const MINUTE: i64 = MINUTE_MILLIS;
+
+    #[test]
+    fn every() {
+        let schedule = Schedule::every(2 * HOUR, 15, 15);
+        assert_eq!(
+            schedule.state(MONDAY),
+            ScheduleState::Upcoming {
+                remaining: 15 * 60_000
+            }
+        );
+        assert_eq!(
+            schedule.state(MONDAY + 20 * MINUTE),
+            ScheduleState::Active {
+                remaining: 10 * 60_000
+            }
+        );
+        assert_eq!(
+            schedule.state(MONDAY + 30 * MINUTE),
+            ScheduleState::Upcoming {
+                remaining: 105 * 60_000
+            }
+        );
+        assert_eq!(
+            schedule.state(MONDAY + 4 * 60 * MINUTE + 15 * MINUTE),
+            ScheduleState::Active {
+                remaining: 15 * 60_000
+            }
+        );
+    }
+
+    #[test]
+    fn daily_weekly() {
+        let daily = Schedule::daily(0, 0);
+        assert_eq!(
+            daily.state(MONDAY + 23 * 60 * MINUTE),
+            ScheduleState::Upcoming {
+                remaining: 60 * 60_000
+            }
+        );
+
+        let weekly = Schedule::weekly(Weekday::Mon, 7 * HOUR + 30, 0);
+        assert_eq!(
+            weekly.state(MONDAY),
+            ScheduleState::Upcoming {
+                remaining: (7 * 60 + 30) * 60_000
+            }
+        );
+        assert_eq!(
+            weekly.state(MONDAY + 8 * 60 * MINUTE),
+            ScheduleState::Upcoming {
+                remaining: (7 * 24 * 60 - 30) * 60_000
+            }
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use super::{DAY, HOUR, Schedule, WEEK};
+use chrono::Weekday;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Error parsing a [`Schedule`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ScheduleError {
+    #[error("Missing interval, use \"every\", \"daily\" or \"weekly\"")]
+    MissingInterval,
+
+    #[error("Missing value after \"{0}\"")]
+    MissingValue(&'static str),
+
+    #[error("Unexpected \"{0}\"")]
+    Unexpected(String),
+
+    #[error("Invalid duration \"{0}\"")]
+    InvalidDuration(String),
+
+    #[error("Invalid time \"{0}\"")]
+    InvalidTime(String),
+
+    #[error("Invalid weekday \"{0}\"")]
+    InvalidWeekday(String),
+
+    #[error("Duration exceeds interval")]
+    DurationExceedsInterval,
+}
+
+impl FromStr for Schedule {
+    type Err = ScheduleError;
+
+    /// Parses a schedule like `every 2h at :15, duration 15m` or `weekly mon at 7:30`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.to_lowercase();
+        let mut tokens = lower
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty());
+
+        let mut period = None;
+        let mut within_day = false;
+        let mut weekday = 0;
+        let mut at = None;
+        let mut duration = 0;
+
+        while let Some(token) = tokens.next() {
+            match token {
+                "every" => {
+                    let value = tokens.next().ok_or(ScheduleError::MissingValue("every"))?;
+                    period = Some(parse_duration(value)?);
+                    within_day = false;
+                }
+                "daily" => {
+                    period = Some(DAY);
+                    within_day = true;
+                }
+                "weekly" => {
+                    let value = tokens.next().ok_or(ScheduleError::MissingValue("weekly"))?;
+                    let day = Weekday::from_str(value)
+                        .map_err(|_| ScheduleError::InvalidWeekday(value.into()))?;
+                    weekday = day.num_days_from_monday() * DAY;
+                    period = Some(WEEK);
+                    within_day = true;
+                }
+                "at" => {
+                    let value = tokens.next().ok_or(ScheduleError::MissingValue("at"))?;
+                    at = Some((parse_time(value)?, value));
+                }
+                "duration" => {
+                    let value = tokens
+                        .next()
+                        .ok_or(ScheduleError::MissingValue("duration"))?;
+                    duration = parse_duration(value)?;
+                }
+                other => return Err(ScheduleError::Unexpected(other.into())),
+            }
+        }
+
+        let period = period.ok_or(ScheduleError::MissingInterval)?;
+        if duration > period {
+            return Err(ScheduleError::DurationExceedsInterval);
+        }
+
+        // daily & weekly times are within a day, others within the interval
+        let limit = if within_day { DAY } else { period };
+        let at = match at {
+            Some((at, value)) if at >= limit => {
+                return Err(ScheduleError::InvalidTime(value.into()));
+            }
+            Some((at, _)) => at,
+            None => 0,
+        };
+        Ok(Self::every(period, weekday + at, duration))
+    }
+}
+
+/// Parses a duration like `1h30m` into minutes.
+fn parse_duration(value: &str) -> Result<u32, ScheduleError> {
+    let invalid = || ScheduleError::InvalidDuration(value.into());
+
+    let mut total = 0;
+    let mut digits = String::new();
+    for c in value.chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+        } else {
+            let amount: u32 = digits.parse().map_err(|_| invalid())?;
+            let unit = match c {
+                'd' => DAY,
+                'h' => HOUR,
+                'm' => 1,
+                _ => return Err(invalid()),
+            };
+            total = amount
+                .checked_mul(unit)
+                .and_then(|amount| total.checked_add(amount))
+                .ok_or_else(invalid)?;
+            digits.clear();
+        }
+    }
+
+    if digits.is_empty() && total > 0 {
+        Ok(total)
+    } else {
+        Err(invalid())
+    }
+}
+
+/// Parses a time of day like `7:30` or `:15` into minutes.
+fn parse_time(value: &str) -> Result<u32, ScheduleError> {
+    let invalid = || ScheduleError::InvalidTime(value.into());
+
+    let (hours, minutes) = value.split_once(':').ok_or_else(invalid)?;
+    let hours: u32 = if hours.is_empty() {
+        0
+    } else {
+        hours.parse().map_err(|_| invalid())?
+    };
+    let minutes: u32 = minutes.parse().map_err(|_| invalid())?;
+
+    if minutes < 60 {
+        hours
+            .checked_mul(HOUR)
+            .and_then(|hours| hours.checked_add(minutes))
+            .ok_or_else(invalid)
+    } else {
+        Err(invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "every 2h at :15, duration 15m".parse(),
+            Ok(Schedule::every(2 * HOUR, 15, 15))
+        );
+        assert_eq!(
+            "Every 3h at 1:30 duration 20m".parse(),
+            Ok(Schedule::every(3 * HOUR, 90, 20))
+        );
+        assert_eq!("daily at 0:00".parse(), Ok(Schedule::daily(0, 0)));
+        assert_eq!(
+            "weekly Mon at 7:30".parse(),
+            Ok(Schedule::weekly(Weekday::Mon, 7 * HOUR + 30, 0))
+        );
+        assert_eq!("every 1h30m".parse(), Ok(Schedule::every(HOUR + 30, 0, 0)));
+    }
+
+    #[test]
+    fn parse_error() {
+        assert_eq!(
+            "at :15".parse::<Schedule>(),
+            Err(ScheduleError::MissingInterval)
+        );
+        assert_eq!(
+            "every".parse::<Schedule>(),
+            Err(ScheduleError::MissingValue("every"))
+        );
+        assert_eq!(
+            "every 2x".parse::<Schedule>(),
+            Err(ScheduleError::InvalidDuration("2x".into()))
+        );
+        assert_eq!(
+            "daily at 25:00".parse::<Schedule>(),
+            Err(ScheduleError::InvalidTime("25:00".into()))
+        );
+        assert_eq!(
+            "every 2h at 2:00".parse::<Schedule>(),
+            Err(ScheduleError::InvalidTime("2:00".into()))
+        );
+        assert_eq!(
+            "weekly someday".parse::<Schedule>(),
+            Err(ScheduleError::InvalidWeekday("someday".into()))
+        );
+        assert_eq!(
+            "every 1h, duration 2h".parse::<Schedule>(),
+            Err(ScheduleError::DurationExceedsInterval)
+        );
+        assert_eq!(
+            "hourly".parse::<Schedule>(),
+            Err(ScheduleError::Unexpected("hourly".into()))
+        );
+    }
+
+    #[test]
+    fn display_roundtrip() {
+        for schedule in [
+            Schedule::every(2 * HOUR, 15, 15),
+            Schedule::every(HOUR + 30, 45, 0),
+            Schedule::daily(0, 0),
+            Schedule::weekly(Weekday::Mon, 7 * HOUR + 30, 0),
+            Schedule::weekly(Weekday::Fri, 18 * HOUR, 2 * HOUR),
+        ] {
+            assert_eq!(schedule.to_string().parse(), Ok(schedule));
+        }
+    }
+}
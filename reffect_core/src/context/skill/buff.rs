@@ -1,5 +1,11 @@
+use crate::{
+    colors::{Color, Colored},
+    named::Named,
+};
+use enumflags2::bitflags;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
-use strum::{AsRefStr, Display, IntoStaticStr};
+use strum::{AsRefStr, Display, EnumIter, IntoStaticStr, VariantArray};
 
 pub type BuffMap = BTreeMap<u32, Buff>;
 
@@ -81,38 +87,106 @@ impl Buff {
 ///
 /// Any category except for Boon and Condition is mapped to [`Category::Effect`].
 #[derive(
-    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Display, AsRefStr, IntoStaticStr,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    AsRefStr,
+    IntoStaticStr,
+    Display,
+    EnumIter,
+    VariantArray,
+    Serialize,
+    Deserialize,
 )]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[bitflags]
+#[repr(u8)]
 pub enum Category {
     /// Buff is a Boon.
-    Boon,
+    Boon = 1 << 0,
 
     /// Buff is an uncategorized effect.
-    Effect,
+    Effect = 1 << 1,
 
     /// Buff is a Condition.
-    Condition,
+    Condition = 1 << 2,
 
     /// Buff is hidden but gives a screen border.
     #[strum(serialize = "Screen Border")]
-    ScreenBorder,
+    ScreenBorder = 1 << 3,
 
     /// Buff is hidden but highlights player in squad.
     #[strum(serialize = "Squad Highlight")]
-    SquadHighlight,
+    SquadHighlight = 1 << 4,
+}
+
+impl Named for Category {
+    fn name(&self) -> &'static str {
+        self.into()
+    }
+
+    fn short_name(&self) -> &'static str {
+        match self {
+            Self::Boon => "Boon",
+            Self::Effect => "Eff",
+            Self::Condition => "Cond",
+            Self::ScreenBorder => "Border",
+            Self::SquadHighlight => "Squad",
+        }
+    }
+}
+
+impl Colored for Category {
+    fn colored(&self) -> Option<Color> {
+        None
+    }
 }
 
 /// Stacking behavior of the buff.
 #[derive(
-    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Display, AsRefStr, IntoStaticStr,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    AsRefStr,
+    IntoStaticStr,
+    Display,
+    EnumIter,
+    VariantArray,
+    Serialize,
+    Deserialize,
 )]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[bitflags]
+#[repr(u8)]
 pub enum Stacking {
     // Other/unknown stacking type.
-    Other,
+    Other = 1 << 0,
 
     /// Buff stacks in intenstity.
-    Intensity,
+    Intensity = 1 << 1,
 
     /// Buff stacks in duration.
-    Duration,
+    Duration = 1 << 2,
+}
+
+impl Named for Stacking {
+    fn name(&self) -> &'static str {
+        self.into()
+    }
+}
+
+impl Colored for Stacking {
+    fn colored(&self) -> Option<Color> {
+        None
+    }
 }
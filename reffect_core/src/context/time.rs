@@ -0,0 +1,177 @@
+use crate::{
+    colors::{Color, Colored},
+    named::Named,
+};
+use enumflags2::bitflags;
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumIter, IntoStaticStr, VariantArray};
+
+/// Milliseconds in a minute.
+const MINUTE: i64 = 60 * 1000;
+
+/// Information about the real-world time.
+#[derive(Debug, Clone)]
+pub struct TimeInfo {
+    /// Current UTC timestamp in milliseconds.
+    pub timestamp: i64,
+}
+
+impl TimeInfo {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self { timestamp: 0 }
+    }
+
+    /// Updates the timestamp.
+    ///
+    /// Returns `true` if a new minute started.
+    pub fn update(&mut self, timestamp: i64) -> bool {
+        let new = self.timestamp.div_euclid(MINUTE) != timestamp.div_euclid(MINUTE);
+        self.timestamp = timestamp;
+        new
+    }
+
+    /// Returns the current day cycle phase and its remaining time in milliseconds.
+    #[inline]
+    pub fn day_cycle(&self) -> (DayCycle, u32) {
+        DayCycle::at(self.timestamp)
+    }
+}
+
+impl Default for TimeInfo {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// Day & night cycle phase of central Tyria.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    AsRefStr,
+    IntoStaticStr,
+    Display,
+    EnumIter,
+    VariantArray,
+    Serialize,
+    Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[bitflags]
+#[repr(u8)]
+pub enum DayCycle {
+    Dawn = 1 << 0,
+    Day = 1 << 1,
+    Dusk = 1 << 2,
+    Night = 1 << 3,
+}
+
+impl DayCycle {
+    /// Length of a full cycle in minutes.
+    pub const CYCLE: i64 = 120;
+
+    /// Phases with their start minute within the cycle.
+    ///
+    /// The cycle starts on every even UTC hour.
+    pub const PHASES: [(Self, i64); 5] = [
+        (Self::Night, 0),
+        (Self::Dawn, 25),
+        (Self::Day, 30),
+        (Self::Dusk, 100),
+        (Self::Night, 105),
+    ];
+
+    /// Returns the phase and its remaining time in milliseconds at the given UTC timestamp.
+    pub fn at(timestamp: i64) -> (Self, u32) {
+        let passed = timestamp.rem_euclid(Self::CYCLE * MINUTE);
+        let mut current = Self::PHASES[0].0;
+        let mut end = Self::CYCLE * MINUTE;
+        for (phase, start) in Self::PHASES.into_iter().rev() {
+            if passed >= start * MINUTE {
+                current = phase;
+                break;
+            }
+            end = start * MINUTE;
+        }
+
+        // night wraps around the cycle end
+        if current == Self::Night && end == Self::CYCLE * MINUTE {
+            end += Self::PHASES[1].1 * MINUTE;
+        }
+
+        (current, (end - passed) as u32)
+    }
+
+    /// Returns the total duration of the phase in milliseconds.
+    pub const fn duration(&self) -> u32 {
+        let minutes = match self {
+            Self::Dawn | Self::Dusk => 5,
+            Self::Day => 70,
+            Self::Night => 40,
+        };
+        minutes * MINUTE as u32
+    }
+}
+
+impl Named for DayCycle {
+    fn name(&self) -> &'static str {
+        self.into()
+    }
+
+    fn short_name(&self) -> &'static str {
+        self.name()
+    }
+}
+
+impl Colored for DayCycle {
+    fn colored(&self) -> Option<Color> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2025-01-01 00:00:00 UTC.
+    const MIDNIGHT: i64 = 1_735_689_600_000;
+
+    #[test]
+    fn day_cycle() {
+        assert_eq!(DayCycle::at(MIDNIGHT), (DayCycle::Night, 25 * 60_000));
+        assert_eq!(
+            DayCycle::at(MIDNIGHT + 27 * MINUTE),
+            (DayCycle::Dawn, 3 * 60_000)
+        );
+        assert_eq!(
+            DayCycle::at(MIDNIGHT + 30 * MINUTE),
+            (DayCycle::Day, 70 * 60_000)
+        );
+        assert_eq!(
+            DayCycle::at(MIDNIGHT + 101 * MINUTE),
+            (DayCycle::Dusk, 4 * 60_000)
+        );
+        assert_eq!(
+            DayCycle::at(MIDNIGHT + 110 * MINUTE),
+            (DayCycle::Night, 35 * 60_000)
+        );
+        assert_eq!(
+            DayCycle::at(MIDNIGHT + 3 * 60 * MINUTE),
+            (DayCycle::Day, 40 * 60_000)
+        );
+    }
+
+    #[test]
+    fn minute_update() {
+        let mut time = TimeInfo::empty();
+        assert!(time.update(MIDNIGHT));
+        assert!(!time.update(MIDNIGHT + 59_999));
+        assert!(time.update(MIDNIGHT + MINUTE));
+    }
+}
@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use std::ffi::{CStr, c_char};
+
+/// User-defined named timer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomTimer {
+    /// Timer name.
+    pub name: String,
+
+    /// Timer duration in milliseconds.
+    pub duration: u32,
+
+    /// Timestamp the timer was started at.
+    #[serde(skip)]
+    pub start: Option<u32>,
+}
+
+impl CustomTimer {
+    /// Prefix for start keybind identifiers.
+    pub const START_PREFIX: &str = "REFFECT_TIMER_START_";
+
+    /// Prefix for cancel keybind identifiers.
+    pub const CANCEL_PREFIX: &str = "REFFECT_TIMER_CANCEL_";
+
+    /// Nexus event identifier to start or restart a timer.
+    pub const START_EVENT: &str = "REFFECT_TIMER_START";
+
+    /// Nexus event identifier to cancel a timer.
+    pub const CANCEL_EVENT: &str = "REFFECT_TIMER_CANCEL";
+
+    /// Creates a new stopped timer with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            duration: 10_000,
+            start: None,
+        }
+    }
+
+    /// Starts or restarts the timer.
+    pub fn start(&mut self, now: u32) {
+        self.start = Some(now);
+    }
+
+    /// Cancels the timer.
+    pub fn cancel(&mut self) {
+        self.start = None;
+    }
+
+    /// Returns the end timestamp if the timer is running.
+    pub fn end(&self) -> Option<u32> {
+        self.start.map(|start| start.saturating_add(self.duration))
+    }
+
+    /// Returns the remaining time in milliseconds if the timer is running.
+    pub fn remaining(&self, now: u32) -> Option<u32> {
+        self.end().filter(|end| *end > now).map(|end| end - now)
+    }
+
+    /// Stops the timer if it has run out.
+    ///
+    /// Returns whether the timer was stopped.
+    pub fn expire(&mut self, now: u32) -> bool {
+        if self.end().is_some_and(|end| now >= end) {
+            self.cancel();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Default for CustomTimer {
+    fn default() -> Self {
+        Self::new(String::new())
+    }
+}
+
+/// Payload of timer Nexus events.
+///
+/// Memory layout is part of the public event interface.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct TimerEvent {
+    /// Null-terminated UTF-8 timer name.
+    pub name: *const c_char,
+}
+
+impl TimerEvent {
+    /// Returns the timer name.
+    ///
+    /// # Safety
+    /// The name pointer has to be null or point to a valid null-terminated string.
+    pub unsafe fn name(&self) -> Option<&str> {
+        if self.name.is_null() {
+            None
+        } else {
+            unsafe { CStr::from_ptr(self.name) }.to_str().ok()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timer() {
+        let mut timer = CustomTimer {
+            duration: 1000,
+            ..CustomTimer::new("Phase")
+        };
+        assert_eq!(timer.remaining(0), None);
+
+        timer.start(500);
+        assert_eq!(timer.remaining(700), Some(800));
+        assert!(!timer.expire(700));
+
+        timer.start(1000);
+        assert_eq!(timer.remaining(1600), Some(400));
+        assert!(timer.expire(2000));
+        assert_eq!(timer.start, None);
+    }
+}
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+
+/// User-defined toggles & profiles switchable via keybinds.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Toggles {
+    /// Named toggles.
+    pub toggles: Vec<CustomToggle>,
+
+    /// Named profiles of enabled toggles.
+    pub profiles: Vec<ToggleProfile>,
+}
+
+impl Toggles {
+    /// Prefix for toggle keybind identifiers.
+    pub const TOGGLE_PREFIX: &str = "REFFECT_TOGGLE_";
+
+    /// Prefix for profile keybind identifiers.
+    pub const PROFILE_PREFIX: &str = "REFFECT_PROFILE_";
+
+    /// Creates new empty toggles.
+    pub const fn new() -> Self {
+        Self {
+            toggles: Vec::new(),
+            profiles: Vec::new(),
+        }
+    }
+
+    /// Checks whether the toggle with the given name is enabled.
+    ///
+    /// Empty or unknown names are always enabled.
+    pub fn is_enabled(&self, name: &str) -> bool {
+        name.is_empty()
+            || self
+                .toggles
+                .iter()
+                .find(|toggle| toggle.name == name)
+                .is_none_or(|toggle| toggle.enabled)
+    }
+
+    /// Flips the toggle with the given name.
+    ///
+    /// Returns `true` if the toggle was found.
+    pub fn toggle(&mut self, name: &str) -> bool {
+        if let Some(toggle) = self.toggles.iter_mut().find(|toggle| toggle.name == name) {
+            toggle.enabled = !toggle.enabled;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Applies the profile with the given name.
+    ///
+    /// Returns `true` if the profile was found.
+    pub fn apply_profile(&mut self, name: &str) -> bool {
+        if let Some(profile) = self.profiles.iter().find(|profile| profile.name == name) {
+            for toggle in &mut self.toggles {
+                toggle.enabled = profile.enabled.contains(&toggle.name);
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Handles the keybind with the given identifier.
+    ///
+    /// Returns `true` if any toggle was affected.
+    pub fn handle_keybind(&mut self, id: &str) -> bool {
+        if let Some(name) = id.strip_prefix(Self::TOGGLE_PREFIX) {
+            self.toggle(name)
+        } else if let Some(name) = id.strip_prefix(Self::PROFILE_PREFIX) {
+            self.apply_profile(name)
+        } else {
+            false
+        }
+    }
+
+    /// Returns the keybind identifiers for all toggles & profiles.
+    pub fn keybind_ids(&self) -> impl Iterator<Item = String> + '_ {
+        let toggles = self
+            .toggles
+            .iter()
+            .map(|toggle| format!("{}{}", Self::TOGGLE_PREFIX, toggle.name));
+        let profiles = self
+            .profiles
+            .iter()
+            .map(|profile| format!("{}{}", Self::PROFILE_PREFIX, profile.name));
+        toggles.chain(profiles)
+    }
+}
+
+/// User-defined named toggle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomToggle {
+    /// Toggle name.
+    pub name: String,
+
+    /// Whether the toggle is currently enabled.
+    pub enabled: bool,
+}
+
+impl CustomToggle {
+    /// Creates a new enabled toggle with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            enabled: true,
+        }
+    }
+}
+
+impl Default for CustomToggle {
+    fn default() -> Self {
+        Self::new(String::new())
+    }
+}
+
+/// User-defined named profile of enabled toggles.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ToggleProfile {
+    /// Profile name.
+    pub name: String,
+
+    /// Names of toggles enabled by the profile, others are disabled.
+    pub enabled: Vec<String>,
+}
+
+impl ToggleProfile {
+    /// Creates a new profile with the given name and enabled toggles.
+    pub fn new(name: impl Into<String>, enabled: Vec<String>) -> Self {
+        Self {
+            name: name.into(),
+            enabled,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keybinds() {
+        let mut toggles = Toggles {
+            toggles: vec![CustomToggle::new("Raid"), CustomToggle::new("Open World")],
+            profiles: vec![ToggleProfile::new("Raid Layout", vec!["Raid".into()])],
+        };
+        assert!(toggles.is_enabled(""));
+        assert!(toggles.is_enabled("Unknown"));
+        assert!(toggles.is_enabled("Raid"));
+
+        assert!(toggles.handle_keybind("REFFECT_TOGGLE_Raid"));
+        assert!(!toggles.is_enabled("Raid"));
+        assert!(toggles.is_enabled("Open World"));
+
+        assert!(toggles.handle_keybind("REFFECT_PROFILE_Raid Layout"));
+        assert!(!toggles.handle_keybind("REFFECT_TOGGLE_Unknown"));
+        assert!(toggles.is_enabled("Raid"));
+        assert!(!toggles.is_enabled("Open World"));
+
+        assert_eq!(
+            toggles.keybind_ids().collect::<Vec<_>>(),
+            [
+                "REFFECT_TOGGLE_Raid",
+                "REFFECT_TOGGLE_Open World",
+                "REFFECT_PROFILE_Raid Layout"
+            ]
+        );
+    }
+}
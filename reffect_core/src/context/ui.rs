@@ -1,11 +1,33 @@
-use crate::links::Links;
+use crate::{
+    colors::{Color, Colored},
+    links::Links,
+    named::Named,
+};
+use enumflags2::{BitFlags, bitflags};
 use nexus::data_link::mumble::UiState;
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumIter, IntoStaticStr, VariantArray};
 
 #[derive(Debug, Clone)]
 pub struct UiInfo {
     pub gameplay: bool,
     pub map_open: bool,
     pub combat: bool,
+
+    /// Whether a text input has focus.
+    pub text_input: bool,
+
+    /// Whether the game window has focus.
+    pub game_focus: bool,
+
+    /// Whether the compass is at the top right.
+    pub compass_top: bool,
+
+    /// Whether in a competitive game mode.
+    pub competitive: bool,
+
+    /// Whether the player is a commander.
+    pub commander: bool,
 }
 
 impl UiInfo {
@@ -14,10 +36,19 @@ impl UiInfo {
             gameplay: false,
             map_open: false,
             combat: false,
+            text_input: false,
+            game_focus: false,
+            compass_top: false,
+            competitive: false,
+            commander: false,
         }
     }
 
-    pub fn update(&mut self, links: &Links) {
+    /// Updates the UI state.
+    ///
+    /// Returns `true` if the map or any UI flag changed.
+    pub fn update(&mut self, links: &Links) -> bool {
+        let before = (self.map_open, self.flags());
         if let Some(nexus) = unsafe { links.nexus() } {
             self.gameplay = nexus.is_gameplay;
         }
@@ -25,12 +56,32 @@ impl UiInfo {
             let ui_state = mumble.read_ui_state();
             self.combat = ui_state.contains(UiState::IS_IN_COMBAT);
             self.map_open = ui_state.contains(UiState::IS_MAP_OPEN);
+            self.text_input = ui_state.contains(UiState::TEXTBOX_HAS_FOCUS);
+            self.game_focus = ui_state.contains(UiState::GAME_HAS_FOCUS);
+            self.compass_top = ui_state.contains(UiState::IS_COMPASS_TOP_RIGHT);
+            self.competitive = ui_state.contains(UiState::IS_IN_COMPETITIVE_GAME_MODE);
         }
+        (self.map_open, self.flags()) != before
     }
 
     pub fn should_show(&self) -> bool {
         self.gameplay && !self.map_open
     }
+
+    /// Returns the current UI flags.
+    pub fn flags(&self) -> BitFlags<UiFlag> {
+        [
+            (UiFlag::TextInput, self.text_input),
+            (UiFlag::GameFocus, self.game_focus),
+            (UiFlag::CompassTop, self.compass_top),
+            (UiFlag::Competitive, self.competitive),
+            (UiFlag::Commander, self.commander),
+        ]
+        .into_iter()
+        .filter(|(_, state)| *state)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
 }
 
 impl Default for UiInfo {
@@ -38,3 +89,77 @@ impl Default for UiInfo {
         Self::new()
     }
 }
+
+/// Game UI state flag.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    AsRefStr,
+    IntoStaticStr,
+    Display,
+    EnumIter,
+    VariantArray,
+    Serialize,
+    Deserialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[bitflags]
+#[repr(u8)]
+pub enum UiFlag {
+    #[strum(serialize = "Text Input")]
+    TextInput = 1 << 0,
+
+    #[strum(serialize = "Game Focus")]
+    GameFocus = 1 << 1,
+
+    #[strum(serialize = "Compass Top")]
+    CompassTop = 1 << 2,
+
+    Competitive = 1 << 3,
+
+    Commander = 1 << 4,
+}
+
+impl Named for UiFlag {
+    fn name(&self) -> &'static str {
+        self.into()
+    }
+
+    fn short_name(&self) -> &'static str {
+        match self {
+            Self::TextInput => "Text",
+            Self::GameFocus => "Focus",
+            Self::CompassTop => "Compass",
+            Self::Competitive => "Comp",
+            Self::Commander => "Cmdr",
+        }
+    }
+}
+
+impl Colored for UiFlag {
+    fn colored(&self) -> Option<Color> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags() {
+        let mut ui = UiInfo::new();
+        assert!(ui.flags().is_empty());
+
+        ui.text_input = true;
+        ui.commander = true;
+        ui.map_open = true;
+        assert_eq!(ui.flags(), UiFlag::TextInput | UiFlag::Commander);
+    }
+}
@@ -7,7 +7,7 @@ pub type Updates = BitFlags<Update>;
 /// An update type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[bitflags]
-#[repr(u16)]
+#[repr(u32)]
 pub enum Update {
     /// Player identity update.
     PlayerIdentity = 1 << 0,
@@ -50,6 +50,24 @@ pub enum Update {
 
     /// Map update.
     Map = 1 << 13,
+
+    /// Real-world time update, happens every minute.
+    Time = 1 << 14,
+
+    /// External variables update.
+    Variables = 1 << 15,
+
+    /// Custom timers update.
+    Timers = 1 << 16,
+
+    /// Toggles update.
+    Toggles = 1 << 17,
+
+    /// Player position update.
+    Position = 1 << 18,
+
+    /// Game UI state update.
+    Ui = 1 << 19,
 }
 
 impl Update {
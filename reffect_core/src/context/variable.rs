@@ -0,0 +1,161 @@
+use std::{
+    collections::BTreeMap,
+    ffi::{CStr, c_char},
+};
+
+/// Variable set externally via Nexus events.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Variable {
+    /// Current value.
+    pub value: f32,
+
+    /// Duration in milliseconds, zero if untimed.
+    pub duration: u32,
+
+    /// Timestamp when the duration ends.
+    pub end: u32,
+}
+
+impl Variable {
+    /// Creates a new variable.
+    pub const fn new(value: f32, duration: u32, now: u32) -> Self {
+        Self {
+            value,
+            duration,
+            end: now.saturating_add(duration),
+        }
+    }
+
+    /// Whether the variable has a duration.
+    pub const fn is_timed(&self) -> bool {
+        self.duration > 0
+    }
+
+    /// Whether the duration of the variable has passed.
+    pub const fn is_expired(&self, now: u32) -> bool {
+        self.is_timed() && now >= self.end
+    }
+
+    /// Returns the remaining time in milliseconds.
+    pub const fn remaining(&self, now: u32) -> u32 {
+        self.end.saturating_sub(now)
+    }
+}
+
+/// Variables set externally via Nexus events.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Variables(BTreeMap<String, Variable>);
+
+impl Variables {
+    /// Nexus event identifier to set a variable.
+    pub const SET_EVENT: &str = "REFFECT_VARIABLE_SET";
+
+    /// Nexus event identifier to remove a variable.
+    pub const REMOVE_EVENT: &str = "REFFECT_VARIABLE_REMOVE";
+
+    /// Creates new empty variables.
+    pub const fn new() -> Self {
+        Self(BTreeMap::new())
+    }
+
+    /// Returns the variable with the given name.
+    pub fn get(&self, name: &str) -> Option<&Variable> {
+        self.0.get(name)
+    }
+
+    /// Returns an iterator over the variable names.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /// Sets the variable with the given name.
+    pub fn set(&mut self, name: impl Into<String>, variable: Variable) {
+        self.0.insert(name.into(), variable);
+    }
+
+    /// Removes the variable with the given name.
+    ///
+    /// Returns whether the variable was present.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.0.remove(name).is_some()
+    }
+
+    /// Removes all expired timed variables.
+    ///
+    /// Returns whether any variable was removed.
+    pub fn remove_expired(&mut self, now: u32) -> bool {
+        let len = self.0.len();
+        self.0.retain(|_, variable| !variable.is_expired(now));
+        self.0.len() != len
+    }
+}
+
+/// Payload of variable Nexus events.
+///
+/// Memory layout is part of the public event interface.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct VariableEvent {
+    /// Null-terminated UTF-8 variable name.
+    pub name: *const c_char,
+
+    /// Variable value, ignored for removal.
+    pub value: f32,
+
+    /// Optional duration in milliseconds, zero if untimed.
+    pub duration: u32,
+}
+
+impl VariableEvent {
+    /// Returns the variable name.
+    ///
+    /// # Safety
+    /// The name pointer has to be null or point to a valid null-terminated string.
+    pub unsafe fn name(&self) -> Option<&str> {
+        if self.name.is_null() {
+            None
+        } else {
+            unsafe { CStr::from_ptr(self.name) }.to_str().ok()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expire() {
+        let mut variables = Variables::new();
+        variables.set("phase", Variable::new(2.0, 0, 1000));
+        variables.set("soon", Variable::new(1.0, 500, 1000));
+
+        assert!(!variables.remove_expired(1200));
+        assert_eq!(
+            variables.get("soon").map(|var| var.remaining(1200)),
+            Some(300)
+        );
+        assert!(variables.remove_expired(1500));
+        assert_eq!(variables.get("soon"), None);
+        assert_eq!(variables.names().collect::<Vec<_>>(), ["phase"]);
+
+        assert!(variables.remove("phase"));
+        assert!(!variables.remove("phase"));
+    }
+
+    #[test]
+    fn event_name() {
+        let event = VariableEvent {
+            name: c"phase".as_ptr(),
+            value: 1.0,
+            duration: 0,
+        };
+        assert_eq!(unsafe { event.name() }, Some("phase"));
+
+        let event = VariableEvent {
+            name: std::ptr::null(),
+            ..event
+        };
+        assert_eq!(unsafe { event.name() }, None);
+    }
+}
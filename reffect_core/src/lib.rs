@@ -20,7 +20,7 @@ pub mod worker;
 
 use crate::{
     context::{ItemInfo, SkillInfo},
-    error::Result,
+    error::{Error, Result},
 };
 use nexus::imgui;
 use windows::Win32::Graphics::Direct3D11::ID3D11ShaderResourceView;
@@ -48,4 +48,11 @@ pub trait Interface {
 
     /// Retrieves skill icon.
     fn get_skill_icon(ui: &Ui, id: u32) -> Option<Texture>;
+
+    /// Retrieves the major trait ids of a specialization line.
+    ///
+    /// Ordered by tier from adept to grandmaster, each from top to bottom.
+    fn get_major_traits(_spec: u32) -> Result<[u32; 9]> {
+        Err(Error::Disabled)
+    }
 }